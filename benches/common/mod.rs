@@ -0,0 +1,12 @@
+//! Inputs shared by the benchmarks.
+
+use chinese_mainland_holidays::HolidayDate;
+
+/// Returns pseudo-random days of 2024 from a fixed linear congruential sequence.
+pub fn dates_2024() -> impl Iterator<Item = HolidayDate> {
+    let mut state = 0x2024_u32;
+    std::iter::from_fn(move || {
+        state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        HolidayDate::from_yo(2024, (state >> 16) as u16 % 366 + 1)
+    })
+}
@@ -0,0 +1,46 @@
+//! Baseline of lookups for catching regressions as the records grow.
+//!
+//! Run with `cargo bench --bench holiday_kind`.
+
+use std::hint::black_box;
+
+use chinese_mainland_holidays::{HolidayDate, HolidayLike};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+mod common;
+
+fn holiday_kind(c: &mut Criterion) {
+    let dates: Vec<_> = common::dates_2024().take(1024).collect();
+    let mut i = 0;
+    c.bench_function("random date", |b| {
+        b.iter(|| {
+            i = (i + 1) % dates.len();
+            black_box(dates[i]).holiday_kind()
+        })
+    });
+
+    let weekday = HolidayDate::from_ymd(2024, 10, 16).unwrap();
+    c.bench_function("regular weekday", |b| {
+        b.iter(|| black_box(weekday).holiday_kind())
+    });
+
+    let recorded = HolidayDate::from_ymd(2024, 10, 4).unwrap();
+    c.bench_function("recorded date", |b| {
+        b.iter(|| black_box(recorded).holiday_kind())
+    });
+
+    let year: Vec<_> = (1..=366)
+        .filter_map(|ordinal| HolidayDate::from_yo(2024, ordinal))
+        .collect();
+    c.bench_function("full year", |b| {
+        b.iter(|| {
+            black_box(&year)
+                .iter()
+                .filter(|date| date.holiday_kind().is_some())
+                .count()
+        })
+    });
+}
+
+criterion_group!(benches, holiday_kind);
+criterion_main!(benches);
@@ -0,0 +1,46 @@
+//! Compares `HolidayLike::is_holiday` on `HolidayDate` with deriving it from the holiday kind.
+//!
+//! Run with `cargo bench --bench is_holiday`.
+
+use std::hint::black_box;
+
+use chinese_mainland_holidays::{HolidayDate, HolidayKind, HolidayLike};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+mod common;
+
+/// Wraps a date to use the default `is_holiday`, which goes through `holiday_kind`.
+struct ViaKind(HolidayDate);
+
+impl HolidayLike for ViaKind {
+    fn holiday_kind(&self) -> Option<HolidayKind> {
+        self.0.holiday_kind()
+    }
+}
+
+fn is_holiday(c: &mut Criterion) {
+    let dates: Vec<_> = common::dates_2024()
+        .filter(|date| !date.is_weekend())
+        .take(1024)
+        .collect();
+
+    let mut group = c.benchmark_group("weekdays");
+    group.bench_function("default is_holiday", |b| {
+        b.iter(|| {
+            for date in &dates {
+                black_box(ViaKind(*black_box(date)).is_holiday());
+            }
+        })
+    });
+    group.bench_function("is_holiday", |b| {
+        b.iter(|| {
+            for date in &dates {
+                black_box(black_box(date).is_holiday());
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, is_holiday);
+criterion_main!(benches);
@@ -0,0 +1,54 @@
+use std::sync::OnceLock;
+
+use crate::{holidays, HolidayDate, HolidayKind, HolidayTable};
+
+static ACTIVE_TABLE: OnceLock<HolidayTable> = OnceLock::new();
+
+/// Returns the process-wide holiday table, the built-in records unless replaced with
+/// [`set_active_table`].
+///
+/// Lookups through [`HolidayLike`](crate::HolidayLike) for [`HolidayDate`], and everything built
+/// on them including [`HolidayCalendar`](crate::HolidayCalendar), use this table once it is set.
+/// The years it supports are those with at least one record. Functions bounded by
+/// [`MIN_YEAR`](crate::MIN_YEAR)..=[`MAX_YEAR`](crate::MAX_YEAR) keep those bounds, and `const`
+/// functions such as [`holiday_kind_of`](crate::holiday_kind_of) always use the built-in records.
+pub fn active_table() -> &'static HolidayTable {
+    ACTIVE_TABLE.get_or_init(|| holidays::records().collect())
+}
+
+/// Replaces the built-in records returned by [`active_table`], e.g. with data loaded at startup.
+///
+/// The table can only be set once, and only before the first call to [`active_table`].
+///
+/// # Errors
+///
+/// Returns the given table back when the active table is already set or in use.
+pub fn set_active_table(table: HolidayTable) -> Result<(), HolidayTable> {
+    ACTIVE_TABLE.set(table)
+}
+
+/// Returns the holiday kind of the date in the active table, or `None` when the active table is
+/// not set, in which case the built-in records apply.
+pub(crate) fn active_kind(date: HolidayDate) -> Option<Option<HolidayKind>> {
+    let table = ACTIVE_TABLE.get()?;
+    if HolidayDate::from_ymd(date.year, date.month, date.day).is_none() {
+        return Some(None);
+    }
+    Some(match table.get(date) {
+        Some(kind) => Some(*kind),
+        None => table.has_year(date.year).then(|| date.regular_kind()),
+    })
+}
+
+/// Returns the day-off status of the records of the year in the active table by key, in order,
+/// `Some(None)` when the table has no records of the year, or `None` when the active table is not
+/// set, in which case the built-in records apply.
+pub(crate) fn active_statuses(year: u16) -> Option<Option<impl Iterator<Item = (u32, bool)>>> {
+    let table = ACTIVE_TABLE.get()?;
+    Some(table.has_year(year).then(|| {
+        table
+            .iter()
+            .filter(move |(date, _)| date.year == year)
+            .map(|(date, kind)| (date.u32_value(), kind.is_day_off()))
+    }))
+}
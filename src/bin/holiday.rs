@@ -0,0 +1,69 @@
+//! Prints whether a date is a holiday in Chinese Mainland.
+//!
+//! ```text
+//! holiday [--json] YYYY-MM-DD
+//! ```
+//!
+//! Exits with 0 for a holiday, 1 for a working day and 2 for an invalid or unsupported date.
+
+use std::process::ExitCode;
+
+use chinese_mainland_holidays::{HolidayDate, HolidayLike};
+
+const USAGE: &str = "usage: holiday [--json] YYYY-MM-DD";
+
+fn main() -> ExitCode {
+    let mut json = false;
+    let mut date = None;
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--json" => json = true,
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return ExitCode::SUCCESS;
+            }
+            _ if date.is_none() => date = Some(arg),
+            _ => {
+                eprintln!("{USAGE}");
+                return ExitCode::from(2);
+            }
+        }
+    }
+    let Some(date) = date else {
+        eprintln!("{USAGE}");
+        return ExitCode::from(2);
+    };
+    let date: HolidayDate = match date.parse() {
+        Ok(date) => date,
+        Err(e) => {
+            eprintln!("holiday: {e}: {date}");
+            return ExitCode::from(2);
+        }
+    };
+    let Some(kind) = date.holiday_kind() else {
+        eprintln!("holiday: unsupported year: {date}");
+        return ExitCode::from(2);
+    };
+    let holiday = date.is_holiday() == Some(true);
+    let festival = if holiday {
+        date.break_festival()
+    } else {
+        kind.festival().map(|festival| festival.name())
+    };
+    let status = if holiday { "holiday" } else { "workday" };
+    if json {
+        let festival = festival.map_or("null".into(), |name| format!("\"{name}\""));
+        println!(
+            "{{\"date\":\"{date}\",\"holiday\":{holiday},\"kind\":\"{kind}\",\"festival\":{festival}}}"
+        );
+    } else if let Some(name) = festival {
+        println!("{status} ({name})");
+    } else {
+        println!("{status}");
+    }
+    if holiday {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,418 @@
+use crate::{HolidayDate, HolidayError, HolidayKind, HolidayLike, HolidayTable};
+
+/// How [`HolidayCalendar::merge`] resolves a date recorded with different kinds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeStrategy {
+    /// Keeps the kind of the calendar merged into.
+    PreferSelf,
+    /// Keeps the kind of the other calendar.
+    PreferOther,
+    /// Fails with [`HolidayError::DuplicateDate`].
+    Error,
+}
+
+/// A holiday calendar layering custom records over the built-in ones, or over the table set with
+/// [`set_active_table`](crate::set_active_table).
+///
+/// Custom records take precedence over the underlying records of the same date. A `Regular*`
+/// record restores a date to its regular kind. Years with custom records are supported in
+/// addition to the years supported by [`HolidayLike::holiday_kind`].
+///
+/// The [`Default`] calendar has no custom records, so it classifies dates exactly as
+/// [`HolidayLike::holiday_kind`] does.
+#[derive(Clone, Default)]
+pub struct HolidayCalendar {
+    records: HolidayTable,
+}
+
+impl HolidayCalendar {
+    /// Constructs a calendar with only the built-in records.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Constructs from custom records in any order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HolidayError::MismatchedKind`] when a `*Holiday` kind falls on Saturday or Sunday,
+    /// a `*Workday` kind falls on a weekday, or a `Regular*` kind contradicts the day of week.
+    /// Returns [`HolidayError::DuplicateDate`] when a date is recorded with different kinds.
+    pub fn from_records(
+        records: impl IntoIterator<Item = (HolidayDate, HolidayKind)>,
+    ) -> Result<Self, HolidayError> {
+        Self::from_table(HolidayTable::try_from_iter(records)?)
+    }
+
+    /// Constructs from a table of custom records.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HolidayError::MismatchedKind`] when a `*Holiday` kind falls on Saturday or Sunday,
+    /// a `*Workday` kind falls on a weekday, or a `Regular*` kind contradicts the day of week.
+    pub fn from_table(records: HolidayTable) -> Result<Self, HolidayError> {
+        for (date, kind) in records.iter() {
+            let regular = matches!(
+                kind,
+                HolidayKind::RegularHoliday | HolidayKind::RegularWorkday
+            );
+            if (kind.is_day_off() == date.regular_kind().is_day_off()) != regular {
+                return Err(HolidayError::MismatchedKind(*date));
+            }
+        }
+        Ok(Self { records })
+    }
+
+    /// Loads custom records from CSV rows of `date,kind`, e.g. `2024-10-01,G1001Holiday`.
+    ///
+    /// Blank lines are skipped, and so is a leading `date,kind` header.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HolidayError::Csv`] with the line number when a row is malformed, has an invalid
+    /// date or an unknown kind. See [`from_records`](Self::from_records) for validation errors.
+    #[cfg(feature = "std")]
+    pub fn load_from_csv(reader: impl std::io::BufRead) -> Result<Self, HolidayError> {
+        let mut records = Vec::new();
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() || i == 0 && line == "date,kind" {
+                continue;
+            }
+            let row = line
+                .split_once(',')
+                .ok_or(HolidayError::InvalidDate)
+                .and_then(|(date, kind)| Ok((date.trim().parse()?, kind.trim().parse()?)));
+            match row {
+                Ok(row) => records.push(row),
+                Err(e) => {
+                    return Err(HolidayError::Csv {
+                        line: i + 1,
+                        source: Box::new(e),
+                    })
+                }
+            }
+        }
+        Self::from_records(records)
+    }
+
+    /// Parses custom records from a TOML array of tables, e.g.
+    ///
+    /// ```toml
+    /// [[holiday]]
+    /// date = "2024-10-01"
+    /// kind = "G1001Holiday"
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HolidayError::Toml`] when the input is not valid TOML,
+    /// [`HolidayError::TomlNotArray`] when the `holiday` key is not an array, and
+    /// [`HolidayError::TomlEntry`] with the entry index when an entry has a missing or invalid date
+    /// or kind. See [`from_records`](Self::from_records) for validation errors.
+    #[cfg(feature = "toml")]
+    pub fn from_toml(s: &str) -> Result<Self, HolidayError> {
+        let table: toml::Table = s.parse().map_err(HolidayError::Toml)?;
+        let Some(entries) = table.get("holiday") else {
+            return Self::from_records([]);
+        };
+        let entries = entries.as_array().ok_or(HolidayError::TomlNotArray)?;
+        let mut records = Vec::with_capacity(entries.len());
+        for (index, entry) in entries.iter().enumerate() {
+            let field = |key| entry.get(key).and_then(|v| v.as_str());
+            let record = field("date")
+                .ok_or(HolidayError::InvalidDate)
+                .and_then(str::parse)
+                .and_then(|date| {
+                    let kind = field("kind").ok_or(HolidayError::UnknownKind)?.parse()?;
+                    Ok((date, kind))
+                })
+                .map_err(|e| HolidayError::TomlEntry {
+                    index,
+                    source: Box::new(e),
+                })?;
+            records.push(record);
+        }
+        Self::from_records(records)
+    }
+
+    /// Formats custom records as TOML accepted by [`from_toml`](Self::from_toml).
+    #[cfg(feature = "toml")]
+    pub fn to_toml(&self) -> alloc::string::String {
+        use core::fmt::Write;
+
+        let mut s = alloc::string::String::new();
+        for (date, kind) in self.records.iter() {
+            let _ = write!(s, "[[holiday]]\ndate = \"{date}\"\nkind = \"{kind}\"\n\n");
+        }
+        s
+    }
+
+    /// Merges the custom records of both calendars.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HolidayError::DuplicateDate`] when a date is recorded with different kinds and the
+    /// strategy is [`MergeStrategy::Error`].
+    pub fn merge(
+        self,
+        other: HolidayCalendar,
+        strategy: MergeStrategy,
+    ) -> Result<Self, HolidayError> {
+        let (first, second) = match strategy {
+            MergeStrategy::PreferSelf => (other, self),
+            MergeStrategy::PreferOther | MergeStrategy::Error => (self, other),
+        };
+        let records = first.records.iter().chain(second.records.iter()).copied();
+        let records = match strategy {
+            MergeStrategy::Error => HolidayTable::try_from_iter(records)?,
+            _ => records.collect(),
+        };
+        Ok(Self { records })
+    }
+
+    /// Returns the custom records.
+    pub fn records(&self) -> &HolidayTable {
+        &self.records
+    }
+
+    /// Returns the holiday kind of the date.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when the year is neither built-in nor has custom records.
+    pub fn holiday_kind(&self, date: HolidayDate) -> Option<HolidayKind> {
+        match self.records.get(date) {
+            Some(kind) => Some(*kind),
+            None => date.holiday_kind().or_else(|| {
+                self.records
+                    .has_year(date.year)
+                    .then(|| date.regular_kind())
+            }),
+        }
+    }
+
+    /// Returns whether the date is a holiday.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when the year is neither built-in nor has custom records.
+    pub fn is_holiday(&self, date: HolidayDate) -> Option<bool> {
+        Some(self.holiday_kind(date)?.is_day_off())
+    }
+
+    /// Returns the first and last day of the run of days off containing January 1 of the year.
+    ///
+    /// This is [`new_year_span`](crate::new_year_span) with the records of this calendar, so the
+    /// run may start in December of the previous year.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when January 1 is a working day, or [`is_holiday`](Self::is_holiday) does.
+    pub fn new_year_span(&self, year: u16) -> Option<(HolidayDate, HolidayDate)> {
+        let date = HolidayDate::from_ymd(year, 1, 1)?;
+        self.is_holiday(date)?
+            .then(|| crate::periods::run_around(date, |d| self.is_holiday(d) == Some(true)))
+    }
+}
+
+/// Constructs a calendar overlaying corrections over the built-in records, for revisions published
+/// after the crate was released.
+///
+/// Corrections take precedence over built-in records of the same date, and a later correction of
+/// the same date takes precedence over an earlier one.
+///
+/// # Errors
+///
+/// Returns [`HolidayError::MismatchedKind`] when a `*Holiday` kind falls on Saturday or Sunday,
+/// a `*Workday` kind falls on a weekday, or a `Regular*` kind contradicts the day of week.
+pub fn apply_patch(patch: &[(HolidayDate, HolidayKind)]) -> Result<HolidayCalendar, HolidayError> {
+    HolidayCalendar::from_table(patch.iter().copied().collect())
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        let calendar = HolidayCalendar::default();
+        assert!(calendar.records().is_empty());
+        for s in [
+            "2024-01-01",
+            "2024-02-04",
+            "2024-10-01",
+            "2024-10-12",
+            "2024-10-13",
+            "2023-10-01",
+        ] {
+            let date = s.parse::<HolidayDate>().unwrap();
+            assert_eq!(calendar.holiday_kind(date), date.holiday_kind(), "{date}");
+        }
+    }
+
+    #[test]
+    fn test_merge() {
+        let date = |s: &str| s.parse::<HolidayDate>().unwrap();
+        let company = HolidayCalendar::from_records([
+            (date("2024-10-08"), HolidayKind::G1001Holiday),
+            (date("2024-10-12"), HolidayKind::RegularHoliday),
+        ])
+        .unwrap();
+        let correction = HolidayCalendar::from_records([
+            (date("2024-10-08"), HolidayKind::RegularWorkday),
+            (date("2025-10-01"), HolidayKind::G1001Holiday),
+        ])
+        .unwrap();
+        let merged = company
+            .clone()
+            .merge(correction.clone(), MergeStrategy::PreferSelf)
+            .unwrap();
+        assert_eq!(merged.records().len(), 3);
+        assert_eq!(merged.is_holiday(date("2024-10-08")), Some(true));
+        assert_eq!(merged.is_holiday(date("2024-10-12")), Some(true));
+        assert_eq!(merged.is_holiday(date("2025-10-01")), Some(true));
+        let merged = company
+            .clone()
+            .merge(correction.clone(), MergeStrategy::PreferOther)
+            .unwrap();
+        assert_eq!(merged.is_holiday(date("2024-10-08")), Some(false));
+        assert_eq!(merged.is_holiday(date("2024-10-12")), Some(true));
+        assert!(matches!(
+            company.merge(correction, MergeStrategy::Error),
+            Err(HolidayError::DuplicateDate(d)) if d == date("2024-10-08")
+        ));
+    }
+
+    #[test]
+    fn test_apply_patch() {
+        let date = |s: &str| s.parse::<HolidayDate>().unwrap();
+        let calendar = apply_patch(&[
+            (date("2024-10-07"), HolidayKind::RegularWorkday),
+            (date("2024-10-08"), HolidayKind::RegularWorkday),
+            (date("2024-10-08"), HolidayKind::G1001Holiday),
+            (date("2024-10-12"), HolidayKind::RegularHoliday),
+        ])
+        .unwrap();
+        assert_eq!(
+            calendar.holiday_kind(date("2024-10-07")),
+            Some(HolidayKind::RegularWorkday)
+        );
+        assert_eq!(
+            calendar.holiday_kind(date("2024-10-08")),
+            Some(HolidayKind::G1001Holiday)
+        );
+        assert_eq!(calendar.is_holiday(date("2024-10-12")), Some(true));
+        assert_eq!(calendar.is_holiday(date("2024-10-01")), Some(true));
+        assert_eq!(calendar.is_holiday(date("2024-09-29")), Some(false));
+        assert!(matches!(
+            apply_patch(&[(date("2024-10-13"), HolidayKind::G1001Holiday)]),
+            Err(HolidayError::MismatchedKind(d)) if d == date("2024-10-13")
+        ));
+    }
+
+    #[test]
+    fn test_load_from_csv() {
+        let csv = "date,kind\n2025-01-01,G0101Holiday\n\n2024-10-12,RegularHoliday\n";
+        let calendar = HolidayCalendar::load_from_csv(csv.as_bytes()).unwrap();
+        let date = |s: &str| s.parse::<HolidayDate>().unwrap();
+        assert_eq!(calendar.is_holiday(date("2025-01-01")), Some(true));
+        assert_eq!(calendar.is_holiday(date("2025-01-02")), Some(false));
+        assert_eq!(calendar.is_holiday(date("2024-10-12")), Some(true));
+        assert_eq!(calendar.is_holiday(date("2024-10-07")), Some(true));
+        assert_eq!(calendar.is_holiday(date("2026-01-01")), None);
+    }
+
+    #[test]
+    fn test_load_from_csv_errors() {
+        assert!(matches!(
+            HolidayCalendar::load_from_csv("2025-01-01,Unknown".as_bytes()),
+            Err(HolidayError::Csv { line: 1, source }) if matches!(*source, HolidayError::UnknownKind)
+        ));
+        assert!(matches!(
+            HolidayCalendar::load_from_csv("2025-01-01,G0101Holiday\n2025-02-30,G0101Holiday".as_bytes()),
+            Err(HolidayError::Csv { line: 2, source }) if matches!(*source, HolidayError::InvalidDate)
+        ));
+        assert!(matches!(
+            HolidayCalendar::load_from_csv("2025-01-04,G0101Holiday".as_bytes()),
+            Err(HolidayError::MismatchedKind(_))
+        ));
+        assert!(matches!(
+            HolidayCalendar::load_from_csv(
+                "2025-01-01,G0101Holiday\n2025-01-01,RegularWorkday".as_bytes()
+            ),
+            Err(HolidayError::DuplicateDate(_))
+        ));
+    }
+
+    #[test]
+    fn test_new_year_span() {
+        let date = |s: &str| s.parse::<HolidayDate>().unwrap();
+        assert_eq!(
+            HolidayCalendar::new().new_year_span(2024),
+            Some((date("2024-01-01"), date("2024-01-01")))
+        );
+        // A synthetic arrangement where the New Year break starts on Monday 2024-12-30.
+        let calendar = HolidayCalendar::from_records([
+            (date("2024-12-30"), HolidayKind::G0101Holiday),
+            (date("2024-12-31"), HolidayKind::G0101Holiday),
+            (date("2025-01-01"), HolidayKind::G0101Holiday),
+            (date("2025-01-04"), HolidayKind::G0101Workday),
+        ])
+        .unwrap();
+        assert_eq!(
+            calendar.new_year_span(2025),
+            Some((date("2024-12-28"), date("2025-01-01")))
+        );
+        assert_eq!(calendar.new_year_span(2026), None);
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_toml() {
+        let toml = r#"
+            [[holiday]]
+            date = "2025-10-01"
+            kind = "G1001Holiday"
+
+            [[holiday]]
+            date = "2025-09-28"
+            kind = "G1001Workday"
+        "#;
+        let calendar = HolidayCalendar::from_toml(toml).unwrap();
+        let date = |s: &str| s.parse::<HolidayDate>().unwrap();
+        assert_eq!(calendar.is_holiday(date("2025-10-01")), Some(true));
+        assert_eq!(calendar.is_holiday(date("2025-09-28")), Some(false));
+        let toml = calendar.to_toml();
+        assert!(toml.starts_with("[[holiday]]\ndate = \"2025-09-28\"\n"));
+        let calendar = HolidayCalendar::from_toml(&toml).unwrap();
+        assert_eq!(calendar.is_holiday(date("2025-10-01")), Some(true));
+        assert_eq!(calendar.is_holiday(date("2025-09-28")), Some(false));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_toml_errors() {
+        assert!(matches!(
+            HolidayCalendar::from_toml("[[holiday]"),
+            Err(HolidayError::Toml(_))
+        ));
+        assert!(matches!(
+            HolidayCalendar::from_toml("[[holiday]]\ndate = \"2025-10-01\"\nkind = \"Unknown\""),
+            Err(HolidayError::TomlEntry { index: 0, source }) if matches!(*source, HolidayError::UnknownKind)
+        ));
+        assert!(matches!(
+            HolidayCalendar::from_toml("[[holiday]]\nkind = \"G1001Holiday\""),
+            Err(HolidayError::TomlEntry { index: 0, source }) if matches!(*source, HolidayError::InvalidDate)
+        ));
+        let e = HolidayCalendar::from_toml("[holiday]\ndate = \"2025-10-01\"")
+            .err()
+            .unwrap();
+        assert!(matches!(e, HolidayError::TomlNotArray));
+        assert_eq!(
+            e.to_string(),
+            "toml key `holiday` is not an array of tables"
+        );
+    }
+}
@@ -1,7 +1,12 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 use chrono::prelude::*;
 
-use crate::{HolidayDate, HolidayKind, HolidayLike};
+use crate::{HolidayDate, HolidayError, HolidayKind, HolidayLike};
 
+/// Years outside `1..=65535` wrap around, so use [`HolidayDate::try_from_naive`] for dates that
+/// may be that far away.
 impl From<NaiveDate> for HolidayDate {
     #[inline]
     fn from(value: NaiveDate) -> Self {
@@ -28,26 +33,96 @@ impl<Tz: TimeZone> From<DateTime<Tz>> for HolidayDate {
     }
 }
 
-macro_rules! impl_meth_holiday_kind {
-    () => {
-        /// An inefficient implementation.
-        #[inline]
-        fn holiday_kind(&self) -> Option<HolidayKind> {
-            HolidayDate::from(self.clone()).holiday_kind()
+impl HolidayDate {
+    /// Converts from a `chrono` date without wrapping years out of range of `u16`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HolidayError::UnsupportedYear`] when the year is less than 1 or greater than
+    /// 65535.
+    pub fn try_from_naive(date: NaiveDate) -> Result<HolidayDate, HolidayError> {
+        match u16::try_from(date.year()) {
+            Ok(1..) => Ok(date.into()),
+            _ => Err(HolidayError::UnsupportedYear(date.year())),
+        }
+    }
+
+    /// Converts from a `chrono` date whose year is supported.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HolidayError::UnsupportedYear`] when the year is less than
+    /// [`MIN_YEAR`](crate::MIN_YEAR) or greater than [`MAX_YEAR`](crate::MAX_YEAR).
+    pub fn try_from_supported(date: NaiveDate) -> Result<HolidayDate, HolidayError> {
+        if (crate::MIN_YEAR as i32..=crate::MAX_YEAR as i32).contains(&date.year()) {
+            Ok(date.into())
+        } else {
+            Err(HolidayError::UnsupportedYear(date.year()))
         }
-    };
+    }
+}
+
+impl TryFrom<HolidayDate> for NaiveDate {
+    type Error = HolidayError;
+
+    /// # Errors
+    ///
+    /// Returns [`HolidayError::InvalidDate`] when the date is out of the range of `NaiveDate`.
+    #[inline]
+    fn try_from(value: HolidayDate) -> Result<Self, Self::Error> {
+        NaiveDate::from_ymd_opt(value.year.into(), value.month.into(), value.day.into())
+            .ok_or(HolidayError::InvalidDate)
+    }
+}
+
+/// Returns the recorded festival holidays of the year as `chrono` dates with their holiday kind,
+/// in order.
+///
+/// Adjusted working days and regular Saturdays and Sundays are not included. Returns an empty `Vec`
+/// when the year is less than [`MIN_YEAR`](crate::MIN_YEAR) or greater than
+/// [`MAX_YEAR`](crate::MAX_YEAR).
+#[cfg(feature = "alloc")]
+pub fn holidays_in_year_naive(year: u16) -> Vec<(NaiveDate, HolidayKind)> {
+    crate::year_map(year)
+        .into_iter()
+        .flatten()
+        .filter(|(_, kind)| kind.is_day_off())
+        .filter_map(|(date, kind)| Some((date.try_into().ok()?, kind)))
+        .collect()
+}
+
+/// Returns the holiday kind of a `chrono` date, or `None` when its year is out of range of `u16`.
+#[inline]
+fn naive_holiday_kind(date: NaiveDate) -> Option<HolidayKind> {
+    HolidayDate::try_from_naive(date).ok()?.holiday_kind()
 }
 
 impl HolidayLike for NaiveDate {
-    impl_meth_holiday_kind!();
+    #[inline]
+    fn holiday_kind(&self) -> Option<HolidayKind> {
+        naive_holiday_kind(*self)
+    }
 }
 
 impl HolidayLike for NaiveDateTime {
-    impl_meth_holiday_kind!();
+    #[inline]
+    fn holiday_kind(&self) -> Option<HolidayKind> {
+        naive_holiday_kind(self.date())
+    }
 }
 
 impl<Tz: TimeZone> HolidayLike for DateTime<Tz> {
-    impl_meth_holiday_kind!();
+    /// An inefficient implementation.
+    #[inline]
+    fn holiday_kind(&self) -> Option<HolidayKind> {
+        let tz = FixedOffset::east_opt(28800).unwrap();
+        naive_holiday_kind(self.with_timezone(&tz).date_naive())
+    }
+
+    #[inline]
+    fn holiday_kind_utc(&self) -> Option<HolidayKind> {
+        naive_holiday_kind(self.naive_utc().date())
+    }
 }
 
 #[cfg(test)]
@@ -59,4 +134,102 @@ mod tests {
         let date: HolidayDate = Utc.with_ymd_and_hms(2024, 10, 1, 20, 0, 0).unwrap().into();
         assert_eq!(date, HolidayDate::from_ymd(2024, 10, 2).unwrap());
     }
+
+    #[test]
+    fn test_naive_date_round_trip() {
+        let naive = NaiveDate::from_ymd_opt(2024, 10, 1).unwrap();
+        let date = HolidayDate::from(naive);
+        assert_eq!(date, HolidayDate::from_ymd(2024, 10, 1).unwrap());
+        assert_eq!(NaiveDate::try_from(date).unwrap(), naive);
+    }
+
+    #[test]
+    fn test_try_from_naive() {
+        let naive = NaiveDate::from_ymd_opt(2024, 10, 1).unwrap();
+        assert_eq!(
+            HolidayDate::try_from_naive(naive).unwrap(),
+            HolidayDate::from_ymd(2024, 10, 1).unwrap()
+        );
+        let naive = NaiveDate::from_ymd_opt(65535, 12, 31).unwrap();
+        assert!(HolidayDate::try_from_naive(naive).is_ok());
+        for year in [70000, 65536, 0, -1] {
+            let naive = NaiveDate::from_ymd_opt(year, 10, 1).unwrap();
+            assert!(
+                matches!(
+                    HolidayDate::try_from_naive(naive),
+                    Err(HolidayError::UnsupportedYear(y)) if y == year
+                ),
+                "{year}"
+            );
+            assert_eq!(naive.holiday_kind(), None, "{year}");
+            assert_eq!(naive.and_hms_opt(12, 0, 0).unwrap().holiday_kind(), None);
+        }
+        // -63512 would wrap around to 2024.
+        let naive = NaiveDate::from_ymd_opt(-63512, 10, 1).unwrap();
+        assert!(matches!(
+            HolidayDate::try_from_naive(naive),
+            Err(HolidayError::UnsupportedYear(-63512))
+        ));
+        assert_eq!(
+            HolidayDate::from(naive).holiday_kind(),
+            Some(HolidayKind::G1001Holiday)
+        );
+        assert_eq!(naive.is_holiday(), None);
+    }
+
+    #[test]
+    fn test_try_from_supported() {
+        let naive = NaiveDate::from_ymd_opt(2024, 10, 1).unwrap();
+        assert_eq!(
+            HolidayDate::try_from_supported(naive).unwrap(),
+            HolidayDate::from_ymd(2024, 10, 1).unwrap()
+        );
+        let naive = NaiveDate::from_ymd_opt(2023, 12, 31).unwrap();
+        let e = HolidayDate::try_from_supported(naive).unwrap_err();
+        assert!(matches!(e, HolidayError::UnsupportedYear(2023)));
+        #[cfg(feature = "std")]
+        assert_eq!(e.to_string(), "year 2023 is not in 2024..=2024");
+        let naive = NaiveDate::from_ymd_opt(-1, 1, 1).unwrap();
+        assert!(matches!(
+            HolidayDate::try_from_supported(naive),
+            Err(HolidayError::UnsupportedYear(-1))
+        ));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_holidays_in_year_naive() {
+        let naive = holidays_in_year_naive(2024);
+        let dates = crate::filter_year(2024, |kind| kind.is_day_off() && kind.festival().is_some())
+            .unwrap();
+        assert_eq!(naive.len(), 19);
+        assert_eq!(naive.len(), dates.len());
+        for ((naive, kind), date) in naive.into_iter().zip(dates) {
+            assert_eq!(HolidayDate::from(naive), date);
+            assert_eq!(Some(kind), date.holiday_kind());
+        }
+        let makeup = NaiveDate::from_ymd_opt(2024, 2, 4).unwrap();
+        assert!(holidays_in_year_naive(2024)
+            .iter()
+            .all(|(date, _)| *date != makeup));
+        assert!(holidays_in_year_naive(2023).is_empty());
+    }
+
+    #[test]
+    fn test_holiday_kind_utc() {
+        let datetime = Utc.with_ymd_and_hms(2024, 9, 30, 20, 0, 0).unwrap();
+        assert!(matches!(
+            datetime.holiday_kind(),
+            Some(HolidayKind::G1001Holiday)
+        ));
+        assert!(matches!(
+            datetime.holiday_kind_utc(),
+            Some(HolidayKind::RegularWorkday)
+        ));
+        let naive = datetime.naive_utc();
+        assert!(matches!(
+            naive.holiday_kind_utc(),
+            Some(HolidayKind::RegularWorkday)
+        ));
+    }
 }
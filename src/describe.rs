@@ -0,0 +1,311 @@
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+
+#[cfg(feature = "alloc")]
+use crate::{days_between, HolidayDate, HolidayLike};
+
+#[cfg(feature = "alloc")]
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// A language for human readable output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Lang {
+    /// English.
+    En,
+    /// Simplified Chinese.
+    Zh,
+}
+
+#[cfg(all(feature = "alloc", feature = "names"))]
+impl HolidayDate {
+    /// Returns a sentence describing the date, e.g. `2024-10-01 is a National Day holiday` or
+    /// `2024年10月1日是国庆节假期`.
+    ///
+    /// Saturdays and Sundays bridged into a break are described as holidays of its festival.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when the year is less than [`MIN_YEAR`](crate::MIN_YEAR) or greater than
+    /// [`MAX_YEAR`](crate::MAX_YEAR).
+    pub fn describe_localized(&self, lang: Lang) -> Option<String> {
+        let kind = self.holiday_kind()?;
+        let festival = if kind.is_day_off() {
+            self.break_festival_of()
+        } else {
+            kind.festival()
+        };
+        let s = match (lang, kind.is_day_off(), festival) {
+            (Lang::En, true, Some(festival)) => {
+                format!("{self} is a {} holiday", festival.name())
+            }
+            (Lang::En, true, None) => format!("{self} is a weekend day off"),
+            (Lang::En, false, Some(festival)) => {
+                format!("{self} is a makeup workday for {}", festival.name())
+            }
+            (Lang::En, false, None) => format!("{self} is a workday"),
+            (Lang::Zh, is_day_off, festival) => {
+                let date = format!("{}年{}月{}日", self.year, self.month, self.day);
+                match (is_day_off, festival) {
+                    (true, Some(festival)) => format!("{date}是{}假期", festival.name_zh()),
+                    (true, None) => format!("{date}是周末休息日"),
+                    (false, Some(festival)) => format!("{date}是{}调休上班日", festival.name_zh()),
+                    (false, None) => format!("{date}是工作日"),
+                }
+            }
+        };
+        Some(s)
+    }
+}
+
+/// Formats the first and last day of a period in English, e.g. `Oct 1 – Oct 7, 2024 (7 days)`
+/// or `Jun 10, 2024 (1 day)`.
+///
+/// The year is written once unless the period spans two years. The days may be given in either
+/// order.
+#[cfg(feature = "alloc")]
+pub fn format_period((start, end): (HolidayDate, HolidayDate)) -> String {
+    let (start, end) = (start.min(end), start.max(end));
+    let days = days_between(start, end) + 1;
+    let plural = if days == 1 { "" } else { "s" };
+    let month = |date: HolidayDate| MONTHS[date.month as usize - 1];
+    if start == end {
+        format!(
+            "{} {}, {} ({days} day{plural})",
+            month(start),
+            start.day,
+            start.year
+        )
+    } else if start.year == end.year {
+        format!(
+            "{} {} – {} {}, {} ({days} day{plural})",
+            month(start),
+            start.day,
+            month(end),
+            end.day,
+            end.year
+        )
+    } else {
+        format!(
+            "{} {}, {} – {} {}, {} ({days} day{plural})",
+            month(start),
+            start.day,
+            start.year,
+            month(end),
+            end.day,
+            end.year
+        )
+    }
+}
+
+/// Formats the first and last day of a period in Chinese, e.g. `2024年10月1日至10月7日（共7天）`
+/// or `2024年6月10日（共1天）`.
+///
+/// The year is written once unless the period spans two years. The days may be given in either
+/// order.
+#[cfg(feature = "alloc")]
+pub fn format_period_zh((start, end): (HolidayDate, HolidayDate)) -> String {
+    let (start, end) = (start.min(end), start.max(end));
+    let days = days_between(start, end) + 1;
+    let start_str = format!("{}年{}月{}日", start.year, start.month, start.day);
+    if start == end {
+        format!("{start_str}（共{days}天）")
+    } else if start.year == end.year {
+        format!("{start_str}至{}月{}日（共{days}天）", end.month, end.day)
+    } else {
+        format!(
+            "{start_str}至{}年{}月{}日（共{days}天）",
+            end.year, end.month, end.day
+        )
+    }
+}
+
+/// Renders the month as a grid of weeks from Monday to Sunday, with a marker after each day:
+/// `H` for a festival holiday, `W` for an adjusted working day, `*` for a regular Saturday or
+/// Sunday off, and none for a regular weekday.
+///
+/// ```text
+/// Oct 2024
+///  Mo  Tu  We  Th  Fr  Sa  Su
+///       1H  2H  3H  4H  5*  6*
+///   7H  8   9  10  11  12W 13*
+/// ```
+///
+/// # Errors
+///
+/// Returns `None` when the month is invalid, or the year is less than
+/// [`MIN_YEAR`](crate::MIN_YEAR) or greater than [`MAX_YEAR`](crate::MAX_YEAR).
+#[cfg(feature = "alloc")]
+pub fn text_calendar(year: u16, month: u8) -> Option<String> {
+    use core::fmt::Write;
+
+    let len = crate::days_in_month(year, month)?;
+    let first = HolidayDate::from_ymd(year, month, 1)?;
+    let mut s = format!(
+        "{} {year}\n Mo  Tu  We  Th  Fr  Sa  Su\n",
+        MONTHS[month as usize - 1]
+    );
+    let mut line = " ".repeat(4 * first.num_days_from_monday() as usize);
+    for day in 1..=len {
+        let date = HolidayDate { year, month, day };
+        let kind = date.holiday_kind()?;
+        let marker = match (kind.is_day_off(), date.is_weekend()) {
+            (true, false) => 'H',
+            (false, true) => 'W',
+            (true, true) => '*',
+            (false, false) => ' ',
+        };
+        let _ = write!(line, "{day:>3}{marker}");
+        if date.num_days_from_monday() == 6 || day == len {
+            s.push_str(line.trim_end());
+            s.push('\n');
+            line.clear();
+        }
+    }
+    Some(s)
+}
+
+/// Renders every month of the year with [`text_calendar`], separated by blank lines, for eyeballing
+/// a year's arrangement when debugging.
+///
+/// # Errors
+///
+/// Returns `None` when the year is less than [`MIN_YEAR`](crate::MIN_YEAR) or greater than
+/// [`MAX_YEAR`](crate::MAX_YEAR).
+#[cfg(feature = "alloc")]
+pub fn print_year(year: u16) -> Option<String> {
+    let mut s = String::new();
+    for month in 1..=12 {
+        if month > 1 {
+            s.push('\n');
+        }
+        s.push_str(&text_calendar(year, month)?);
+    }
+    Some(s)
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "names")]
+    #[test]
+    fn test_describe_localized() {
+        let describe = |y, m, d, lang| {
+            HolidayDate::from_ymd(y, m, d)
+                .unwrap()
+                .describe_localized(lang)
+        };
+        assert_eq!(
+            describe(2024, 10, 1, Lang::En).unwrap(),
+            "2024-10-01 is a National Day holiday"
+        );
+        assert_eq!(
+            describe(2024, 10, 1, Lang::Zh).unwrap(),
+            "2024年10月1日是国庆节假期"
+        );
+        assert_eq!(
+            describe(2024, 10, 5, Lang::En).unwrap(),
+            "2024-10-05 is a National Day holiday"
+        );
+        assert_eq!(
+            describe(2024, 10, 12, Lang::En).unwrap(),
+            "2024-10-12 is a makeup workday for National Day"
+        );
+        assert_eq!(
+            describe(2024, 10, 12, Lang::Zh).unwrap(),
+            "2024年10月12日是国庆节调休上班日"
+        );
+        assert_eq!(
+            describe(2024, 10, 19, Lang::En).unwrap(),
+            "2024-10-19 is a weekend day off"
+        );
+        assert_eq!(
+            describe(2024, 10, 8, Lang::Zh).unwrap(),
+            "2024年10月8日是工作日"
+        );
+        assert_eq!(describe(2023, 10, 1, Lang::En), None);
+    }
+
+    #[test]
+    fn test_text_calendar() {
+        assert_eq!(
+            text_calendar(2024, 10).unwrap(),
+            "\
+Oct 2024
+ Mo  Tu  We  Th  Fr  Sa  Su
+      1H  2H  3H  4H  5*  6*
+  7H  8   9  10  11  12W 13*
+ 14  15  16  17  18  19* 20*
+ 21  22  23  24  25  26* 27*
+ 28  29  30  31
+"
+        );
+        assert_eq!(text_calendar(2024, 13), None);
+        assert_eq!(text_calendar(2023, 10), None);
+    }
+
+    #[test]
+    fn test_print_year() {
+        let s = print_year(2024).unwrap();
+        assert!(s.starts_with("Jan 2024\n Mo  Tu  We  Th  Fr  Sa  Su\n  1H  2   3"));
+        assert!(s.contains(
+            "\
+Sep 2024
+ Mo  Tu  We  Th  Fr  Sa  Su
+                          1*
+  2   3   4   5   6   7*  8*
+  9  10  11  12  13  14W 15*
+ 16H 17H 18  19  20  21* 22*
+ 23  24  25  26  27  28* 29W
+ 30
+
+Oct 2024
+ Mo  Tu  We  Th  Fr  Sa  Su
+      1H  2H  3H  4H  5*  6*
+  7H  8   9  10  11  12W 13*
+ 14  15  16  17  18  19* 20*
+ 21  22  23  24  25  26* 27*
+ 28  29  30  31
+
+Nov 2024
+"
+        ));
+        assert_eq!(s.matches(" Mo  Tu").count(), 12);
+        assert_eq!(print_year(2023), None);
+    }
+
+    #[test]
+    fn test_format_period() {
+        let date = |y, m, d| HolidayDate::from_ymd(y, m, d).unwrap();
+        let national_day = (date(2024, 10, 1), date(2024, 10, 7));
+        assert_eq!(format_period(national_day), "Oct 1 – Oct 7, 2024 (7 days)");
+        assert_eq!(
+            format_period_zh(national_day),
+            "2024年10月1日至10月7日（共7天）"
+        );
+        let dragon_boat = date(2024, 6, 1).next_holiday_period().unwrap();
+        assert_eq!(format_period(dragon_boat), "Jun 8 – Jun 10, 2024 (3 days)");
+        assert_eq!(
+            format_period_zh(dragon_boat),
+            "2024年6月8日至6月10日（共3天）"
+        );
+        let single = (date(2024, 6, 10), date(2024, 6, 10));
+        assert_eq!(format_period(single), "Jun 10, 2024 (1 day)");
+        assert_eq!(format_period_zh(single), "2024年6月10日（共1天）");
+        let new_year = (date(2024, 12, 30), date(2025, 1, 1));
+        assert_eq!(
+            format_period(new_year),
+            "Dec 30, 2024 – Jan 1, 2025 (3 days)"
+        );
+        assert_eq!(
+            format_period_zh(new_year),
+            "2024年12月30日至2025年1月1日（共3天）"
+        );
+        let reversed = (date(2024, 10, 7), date(2024, 10, 1));
+        assert_eq!(format_period(reversed), format_period(national_day));
+        assert_eq!(format_period_zh(reversed), format_period_zh(national_day));
+    }
+}
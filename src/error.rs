@@ -0,0 +1,100 @@
+use core::fmt;
+
+use crate::HolidayDate;
+
+/// Errors from parsing or validating holiday data.
+///
+/// This enum is marked non_exhaustive to accomodate new data sources.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum HolidayError {
+    /// A date is malformed or does not exist.
+    InvalidDate,
+    /// A holiday kind name is not recognized.
+    UnknownKind,
+    /// A festival name is not recognized.
+    UnknownFestival,
+    /// A holiday kind contradicts the day of week of its date.
+    MismatchedKind(HolidayDate),
+    /// A date is recorded more than once with different kinds.
+    DuplicateDate(HolidayDate),
+    /// Records are not in ascending order of date.
+    UnsortedRecords,
+    /// A year is less than [`MIN_YEAR`](crate::MIN_YEAR) or greater than
+    /// [`MAX_YEAR`](crate::MAX_YEAR).
+    UnsupportedYear(i32),
+    /// A CSV row is invalid, with its 1-based line number.
+    #[cfg(feature = "std")]
+    Csv {
+        line: usize,
+        source: Box<HolidayError>,
+    },
+    /// A TOML `[[holiday]]` entry is invalid, with its 0-based index.
+    #[cfg(feature = "toml")]
+    TomlEntry {
+        index: usize,
+        source: Box<HolidayError>,
+    },
+    /// The TOML `holiday` key is not an array of tables.
+    #[cfg(feature = "toml")]
+    TomlNotArray,
+    /// The input is not valid TOML.
+    #[cfg(feature = "toml")]
+    Toml(toml::de::Error),
+    /// Reading the input failed.
+    #[cfg(feature = "std")]
+    Io(std::io::Error),
+}
+
+impl fmt::Display for HolidayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidDate => f.write_str("invalid date"),
+            Self::UnknownKind => f.write_str("unknown holiday kind"),
+            Self::UnknownFestival => f.write_str("unknown festival"),
+            Self::MismatchedKind(date) => {
+                write!(f, "holiday kind contradicts the day of week of {date}")
+            }
+            Self::DuplicateDate(date) => write!(f, "conflicting kinds for {date}"),
+            Self::UnsortedRecords => f.write_str("records are not sorted by date"),
+            Self::UnsupportedYear(year) => write!(
+                f,
+                "year {year} is not in {}..={}",
+                crate::MIN_YEAR,
+                crate::MAX_YEAR
+            ),
+            #[cfg(feature = "std")]
+            Self::Csv { line, source } => write!(f, "line {line}: {source}"),
+            #[cfg(feature = "toml")]
+            Self::TomlEntry { index, source } => write!(f, "holiday entry {index}: {source}"),
+            #[cfg(feature = "toml")]
+            Self::TomlNotArray => f.write_str("toml key `holiday` is not an array of tables"),
+            #[cfg(feature = "toml")]
+            Self::Toml(e) => write!(f, "toml error: {e}"),
+            #[cfg(feature = "std")]
+            Self::Io(e) => write!(f, "io error: {e}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HolidayError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Csv { source, .. } => Some(source.as_ref()),
+            #[cfg(feature = "toml")]
+            Self::TomlEntry { source, .. } => Some(source.as_ref()),
+            #[cfg(feature = "toml")]
+            Self::Toml(e) => Some(e),
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for HolidayError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
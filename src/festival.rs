@@ -0,0 +1,497 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+use crate::{holidays, HolidayDate, HolidayError, HolidayKind, HolidayLike};
+
+/// A festival with statutory holidays.
+///
+/// Festivals are ordered by their usual sequence in the Gregorian year, from New Year to National
+/// Day. Lunar festivals shift between years, so this is not necessarily the order of their dates
+/// in a given year.
+///
+/// This enum is marked non_exhaustive to accomodate newly established holidays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Festival {
+    /// New Year, on January 1.
+    NewYear,
+    /// Spring Festival, or Chinese New Year, on the first day of the first lunar month.
+    SpringFestival,
+    /// Qingming Festival, on the fifth solar term.
+    Qingming,
+    /// Labour Day, or May Day, on May 1.
+    LaborDay,
+    /// Dragon Boat Festival, on the fifth day of the fifth lunar month.
+    DragonBoat,
+    /// Mid-Autumn Festival, on the fifteenth day of the eighth lunar month.
+    MidAutumn,
+    /// National Day, on October 1.
+    NationalDay,
+}
+
+impl Festival {
+    /// Returns the English name, e.g. `National Day`.
+    #[cfg(feature = "names")]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::NewYear => "New Year",
+            Self::SpringFestival => "Spring Festival",
+            Self::Qingming => "Qingming Festival",
+            Self::LaborDay => "Labour Day",
+            Self::DragonBoat => "Dragon Boat Festival",
+            Self::MidAutumn => "Mid-Autumn Festival",
+            Self::NationalDay => "National Day",
+        }
+    }
+
+    /// Returns the Chinese name, e.g. `国庆节`.
+    #[cfg(feature = "names")]
+    pub const fn name_zh(&self) -> &'static str {
+        match self {
+            Self::NewYear => "元旦",
+            Self::SpringFestival => "春节",
+            Self::Qingming => "清明节",
+            Self::LaborDay => "劳动节",
+            Self::DragonBoat => "端午节",
+            Self::MidAutumn => "中秋节",
+            Self::NationalDay => "国庆节",
+        }
+    }
+
+    /// Returns the fixed month and day of a festival on the Gregorian calendar, e.g. `(10, 1)` for
+    /// National Day, for exporting yearly recurring events such as an iCalendar `RRULE`.
+    ///
+    /// Returns `None` for festivals on the lunar calendar or solar terms, whose Gregorian dates vary
+    /// by year.
+    pub const fn gregorian_recurrence(&self) -> Option<(u8, u8)> {
+        match self {
+            Self::NewYear => Some((1, 1)),
+            Self::LaborDay => Some((5, 1)),
+            Self::NationalDay => Some((10, 1)),
+            Self::SpringFestival | Self::Qingming | Self::DragonBoat | Self::MidAutumn => None,
+        }
+    }
+}
+
+/// Parses a snake_case name, one of `new_year`, `spring_festival`, `qingming`, `labor_day`,
+/// `dragon_boat`, `mid_autumn` and `national_day`.
+impl FromStr for Festival {
+    type Err = HolidayError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "new_year" => Ok(Self::NewYear),
+            "spring_festival" => Ok(Self::SpringFestival),
+            "qingming" => Ok(Self::Qingming),
+            "labor_day" => Ok(Self::LaborDay),
+            "dragon_boat" => Ok(Self::DragonBoat),
+            "mid_autumn" => Ok(Self::MidAutumn),
+            "national_day" => Ok(Self::NationalDay),
+            _ => Err(HolidayError::UnknownFestival),
+        }
+    }
+}
+
+/// Returns the Spring Festival of the year, the first day of the first lunar month, e.g.
+/// 2024-02-10, regardless of which days the public holiday spans.
+///
+/// # Errors
+///
+/// Returns `None` when the year is less than [`MIN_YEAR`](crate::MIN_YEAR) or greater than
+/// [`MAX_YEAR`](crate::MAX_YEAR).
+pub fn spring_festival_day1(year: u16) -> Option<HolidayDate> {
+    let i = year.checked_sub(crate::MIN_YEAR)?;
+    holidays::SPRING_FESTIVALS.get(i as usize).copied()
+}
+
+/// Returns the number of festival holidays the festival has in the year, i.e. its recorded
+/// `*Holiday` days, e.g. 5 for National Day 2024, as opposed to the length of its break.
+///
+/// This includes weekdays swapped with an adjusted working day. See
+/// [`HolidayDate::is_triple_pay_day`] for the statutory holidays themselves.
+///
+/// # Errors
+///
+/// Returns `None` when the year is less than [`MIN_YEAR`](crate::MIN_YEAR) or greater than
+/// [`MAX_YEAR`](crate::MAX_YEAR).
+pub fn statutory_days(year: u16, festival: Festival) -> Option<u32> {
+    if !(crate::MIN_YEAR..=crate::MAX_YEAR).contains(&year) {
+        return None;
+    }
+    let count = holidays::HOLIDAYS
+        .iter()
+        .filter(|(v, kind)| {
+            HolidayDate::from_u32_value(*v).year == year
+                && kind.is_day_off()
+                && kind.festival() == Some(festival)
+        })
+        .count();
+    Some(count as u32)
+}
+
+/// Returns whether the records of the year plausibly cover the whole year, i.e. every festival has
+/// a record and the last record is in October or later, to flag partially entered data.
+///
+/// # Errors
+///
+/// Returns `None` when the year is less than [`MIN_YEAR`](crate::MIN_YEAR) or greater than
+/// [`MAX_YEAR`](crate::MAX_YEAR).
+pub fn year_data_complete(year: u16) -> Option<bool> {
+    if !(crate::MIN_YEAR..=crate::MAX_YEAR).contains(&year) {
+        return None;
+    }
+    let mut records = holidays::HOLIDAYS
+        .iter()
+        .map(|(v, kind)| (HolidayDate::from_u32_value(*v), *kind))
+        .filter(|(date, _)| date.year == year);
+    let all_festivals = [
+        Festival::NewYear,
+        Festival::SpringFestival,
+        Festival::Qingming,
+        Festival::LaborDay,
+        Festival::DragonBoat,
+        Festival::MidAutumn,
+        Festival::NationalDay,
+    ]
+    .into_iter()
+    .all(|festival| {
+        records
+            .clone()
+            .any(|(_, kind)| kind.festival() == Some(festival))
+    });
+    Some(
+        all_festivals
+            && records
+                .next_back()
+                .is_some_and(|(date, _)| date.month >= 10),
+    )
+}
+
+/// Returns the number of consecutive days off of the festival's break in the year, including
+/// bridged Saturdays and Sundays, e.g. 7 for National Day 2024, as opposed to
+/// [`statutory_days`].
+///
+/// # Errors
+///
+/// Returns `None` when the festival has no holiday recorded in the year, the year is less than
+/// [`MIN_YEAR`](crate::MIN_YEAR) or greater than [`MAX_YEAR`](crate::MAX_YEAR), or the break
+/// reaches [`MIN_DATE`](crate::MIN_DATE) or [`MAX_DATE`](crate::MAX_DATE), where the days beyond
+/// cannot be classified to complete it.
+pub fn observed_break_length(year: u16, festival: Festival) -> Option<u32> {
+    let (day_off, _) = holidays::HOLIDAYS.iter().find(|(v, kind)| {
+        HolidayDate::from_u32_value(*v).year == year
+            && kind.is_day_off()
+            && kind.festival() == Some(festival)
+    })?;
+    let (start, end) = HolidayDate::from_u32_value(*day_off).containing_span()?;
+    start.pred()?.holiday_kind()?;
+    end.succ()?.holiday_kind()?;
+    Some((crate::days_between(start, end) + 1) as u32)
+}
+
+/// Returns each adjusted working day of the year in order, with the weekday holidays swapped with
+/// it, i.e. the days off it funds.
+///
+/// See [`swap_pair`](crate::swap_pair) for how the swaps are paired. The days off of each working
+/// day are its [`HolidayDate::paired_holiday_days`].
+///
+/// # Errors
+///
+/// Returns `None` when the year is less than [`MIN_YEAR`](crate::MIN_YEAR) or greater than
+/// [`MAX_YEAR`](crate::MAX_YEAR).
+#[cfg(feature = "alloc")]
+pub fn compensation_pairs(year: u16) -> Option<Vec<(HolidayDate, Vec<HolidayDate>)>> {
+    if !(crate::MIN_YEAR..=crate::MAX_YEAR).contains(&year) {
+        return None;
+    }
+    Some(
+        holidays::records()
+            .filter(|(date, kind)| date.year == year && !kind.is_day_off())
+            .filter_map(|(date, _)| Some((date, date.paired_holiday_days()?)))
+            .collect(),
+    )
+}
+
+impl HolidayKind {
+    /// Returns the festival of a festival holiday or adjusted working day.
+    ///
+    /// Returns `None` for [`HolidayKind::RegularHoliday`] and [`HolidayKind::RegularWorkday`].
+    pub const fn festival(&self) -> Option<Festival> {
+        use HolidayKind::*;
+        match self {
+            RegularHoliday | RegularWorkday => None,
+            G0101Holiday | G0101Workday => Some(Festival::NewYear),
+            L0101Holiday | L0101Workday => Some(Festival::SpringFestival),
+            S05Holiday | S05Workday => Some(Festival::Qingming),
+            G0501Holiday | G0501Workday => Some(Festival::LaborDay),
+            L0505Holiday | L0505Workday => Some(Festival::DragonBoat),
+            L0815Holiday | L0815Workday => Some(Festival::MidAutumn),
+            G1001Holiday | G1001Workday => Some(Festival::NationalDay),
+        }
+    }
+}
+
+impl HolidayDate {
+    /// Returns the 1-based lunar day of this date in the Spring Festival break, e.g. 1 for the
+    /// first day of the first lunar month, 大年初一.
+    ///
+    /// The count starts from [`spring_festival_day1`], which is also the first day of the 2024
+    /// break. A Spring Festival eve inside a break precedes day 1 and has no number.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when this date is not in the run of days off containing the Spring Festival,
+    /// or is before it.
+    pub fn spring_festival_day_number(&self) -> Option<u8> {
+        let day1 = spring_festival_day1(self.year)?;
+        let (start, end) = self.containing_span()?;
+        if !(start <= day1 && day1 <= *self && *self <= end) {
+            return None;
+        }
+        u8::try_from(crate::days_between(day1, *self) + 1).ok()
+    }
+
+    /// Returns the festival holidays that this adjusted working day is swapped with, in order.
+    ///
+    /// Only the holidays moved onto weekdays by the swap are returned, not the statutory days of
+    /// the festival or the weekends moved into its break.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when this date is not an adjusted working day, or the year is less than
+    /// [`MIN_YEAR`](crate::MIN_YEAR) or greater than [`MAX_YEAR`](crate::MAX_YEAR).
+    #[cfg(feature = "alloc")]
+    pub fn paired_holiday_days(&self) -> Option<Vec<HolidayDate>> {
+        let kind = crate::holiday_kind_of(*self)?;
+        if kind.is_day_off() || kind.festival().is_none() {
+            return None;
+        }
+        let v = self.u32_value();
+        Some(
+            holidays::SWAPS
+                .iter()
+                .filter(|(workday, _)| *workday == v)
+                .map(|(_, holiday)| HolidayDate::from_u32_value(*holiday))
+                .collect(),
+        )
+    }
+
+    /// Returns a short explanation of a festival holiday or adjusted working day, e.g.
+    /// `Spring Festival public holiday` or
+    /// `Makeup workday compensating the Spring Festival holiday`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` for a regular Saturday, Sunday or weekday, or when the year is less than
+    /// [`MIN_YEAR`](crate::MIN_YEAR) or greater than [`MAX_YEAR`](crate::MAX_YEAR).
+    #[cfg(feature = "names")]
+    pub fn observance_note(&self) -> Option<&'static str> {
+        use HolidayKind::*;
+        match self.holiday_kind()? {
+            RegularHoliday | RegularWorkday => None,
+            G0101Holiday => Some("New Year public holiday"),
+            G0101Workday => Some("Makeup workday compensating the New Year holiday"),
+            L0101Holiday => Some("Spring Festival public holiday"),
+            L0101Workday => Some("Makeup workday compensating the Spring Festival holiday"),
+            S05Holiday => Some("Qingming Festival public holiday"),
+            S05Workday => Some("Makeup workday compensating the Qingming Festival holiday"),
+            G0501Holiday => Some("Labour Day public holiday"),
+            G0501Workday => Some("Makeup workday compensating the Labour Day holiday"),
+            L0505Holiday => Some("Dragon Boat Festival public holiday"),
+            L0505Workday => Some("Makeup workday compensating the Dragon Boat Festival holiday"),
+            L0815Holiday => Some("Mid-Autumn Festival public holiday"),
+            L0815Workday => Some("Makeup workday compensating the Mid-Autumn Festival holiday"),
+            G1001Holiday => Some("National Day public holiday"),
+            G1001Workday => Some("Makeup workday compensating the National Day holiday"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_festival() {
+        assert_eq!(HolidayKind::RegularHoliday.festival(), None);
+        assert_eq!(
+            HolidayKind::L0101Workday.festival(),
+            Some(Festival::SpringFestival)
+        );
+    }
+
+    #[cfg(feature = "names")]
+    #[test]
+    fn test_name() {
+        assert_eq!(
+            HolidayKind::G1001Holiday.festival().unwrap().name(),
+            "National Day"
+        );
+        assert_eq!(Festival::SpringFestival.name_zh(), "春节");
+    }
+
+    #[cfg(feature = "names")]
+    #[test]
+    fn test_observance_note() {
+        let note = |y, m, d| HolidayDate::from_ymd(y, m, d).unwrap().observance_note();
+        assert_eq!(
+            note(2024, 2, 4),
+            Some("Makeup workday compensating the Spring Festival holiday")
+        );
+        assert_eq!(note(2024, 2, 12), Some("Spring Festival public holiday"));
+        assert_eq!(note(2024, 2, 10), None);
+        assert_eq!(note(2024, 10, 8), None);
+        assert_eq!(note(2023, 10, 1), None);
+    }
+
+    #[test]
+    fn test_spring_festival_day1() {
+        let date = spring_festival_day1(2024).unwrap();
+        assert_eq!(date, HolidayDate::from_ymd(2024, 2, 10).unwrap());
+        assert_eq!(date.holiday_kind(), Some(HolidayKind::RegularHoliday));
+        assert_eq!(date.containing_span().unwrap().0, date);
+        assert_eq!(spring_festival_day1(2023), None);
+        assert_eq!(spring_festival_day1(2025), None);
+    }
+
+    #[test]
+    fn test_spring_festival_day_number() {
+        let number = |m, d| {
+            HolidayDate::from_ymd(2024, m, d)
+                .unwrap()
+                .spring_festival_day_number()
+        };
+        assert_eq!(number(2, 9), None);
+        assert_eq!(number(2, 10), Some(1));
+        assert_eq!(number(2, 11), Some(2));
+        assert_eq!(number(2, 12), Some(3));
+        assert_eq!(number(2, 17), Some(8));
+        assert_eq!(number(2, 18), None);
+        assert_eq!(number(2, 24), None);
+        assert_eq!(number(10, 1), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_paired_holiday_days() {
+        let date = |m, d| HolidayDate::from_ymd(2024, m, d).unwrap();
+        assert_eq!(
+            date(9, 14).paired_holiday_days(),
+            Some(alloc::vec![date(9, 16)])
+        );
+        assert_eq!(
+            date(2, 4).paired_holiday_days(),
+            Some(alloc::vec![date(2, 15)])
+        );
+        assert_eq!(
+            date(10, 12).paired_holiday_days(),
+            Some(alloc::vec![date(10, 7)])
+        );
+        assert_eq!(date(9, 16).paired_holiday_days(), None);
+        assert_eq!(date(9, 13).paired_holiday_days(), None);
+        assert_eq!(date(9, 15).paired_holiday_days(), None);
+    }
+
+    #[test]
+    fn test_statutory_days() {
+        assert_eq!(statutory_days(2024, Festival::NationalDay), Some(5));
+        assert_eq!(statutory_days(2024, Festival::SpringFestival), Some(5));
+        assert_eq!(statutory_days(2024, Festival::NewYear), Some(1));
+        assert_eq!(statutory_days(2024, Festival::LaborDay), Some(3));
+        assert_eq!(statutory_days(2024, Festival::MidAutumn), Some(2));
+        assert_eq!(statutory_days(2023, Festival::NationalDay), None);
+    }
+
+    #[test]
+    fn test_year_data_complete() {
+        assert_eq!(year_data_complete(2024), Some(true));
+        assert_eq!(year_data_complete(2023), None);
+        assert_eq!(year_data_complete(2025), None);
+    }
+
+    #[test]
+    fn test_observed_break_length() {
+        assert_eq!(observed_break_length(2024, Festival::NationalDay), Some(7));
+        assert_eq!(
+            observed_break_length(2024, Festival::SpringFestival),
+            Some(8)
+        );
+        assert_eq!(observed_break_length(2024, Festival::Qingming), Some(3));
+        assert_eq!(observed_break_length(2024, Festival::LaborDay), Some(5));
+        assert_eq!(observed_break_length(2024, Festival::DragonBoat), Some(3));
+        assert_eq!(observed_break_length(2024, Festival::MidAutumn), Some(3));
+        assert_eq!(observed_break_length(2024, Festival::NewYear), None);
+        assert_eq!(observed_break_length(2023, Festival::NationalDay), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_compensation_pairs() {
+        let date = |m, d| HolidayDate::from_ymd(2024, m, d).unwrap();
+        let pairs = compensation_pairs(2024).unwrap();
+        assert_eq!(pairs.len(), 8);
+        assert_eq!(pairs[0], (date(2, 4), alloc::vec![date(2, 15)]));
+        assert_eq!(pairs[6], (date(9, 29), alloc::vec![date(10, 4)]));
+        assert_eq!(pairs[7], (date(10, 12), alloc::vec![date(10, 7)]));
+        assert!(pairs.windows(2).all(|w| w[0].0 < w[1].0));
+        for (workday, days_off) in &pairs {
+            assert_eq!(workday.paired_holiday_days().as_ref(), Some(days_off));
+        }
+        assert_eq!(compensation_pairs(2023), None);
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "national_day".parse::<Festival>().unwrap(),
+            Festival::NationalDay
+        );
+        assert_eq!(
+            "spring_festival".parse::<Festival>().unwrap(),
+            Festival::SpringFestival
+        );
+        assert_eq!("labor_day".parse::<Festival>().unwrap(), Festival::LaborDay);
+        for s in ["NationalDay", "national day", "G1001Holiday", ""] {
+            assert!(
+                matches!(s.parse::<Festival>(), Err(HolidayError::UnknownFestival)),
+                "{s}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_ord() {
+        let mut festivals = [
+            Festival::NationalDay,
+            Festival::Qingming,
+            Festival::NewYear,
+            Festival::MidAutumn,
+            Festival::SpringFestival,
+            Festival::DragonBoat,
+            Festival::LaborDay,
+        ];
+        festivals.sort();
+        assert_eq!(
+            festivals,
+            [
+                Festival::NewYear,
+                Festival::SpringFestival,
+                Festival::Qingming,
+                Festival::LaborDay,
+                Festival::DragonBoat,
+                Festival::MidAutumn,
+                Festival::NationalDay,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_gregorian_recurrence() {
+        assert_eq!(Festival::NewYear.gregorian_recurrence(), Some((1, 1)));
+        assert_eq!(Festival::LaborDay.gregorian_recurrence(), Some((5, 1)));
+        assert_eq!(Festival::NationalDay.gregorian_recurrence(), Some((10, 1)));
+        assert_eq!(Festival::SpringFestival.gregorian_recurrence(), None);
+        assert_eq!(Festival::Qingming.gregorian_recurrence(), None);
+    }
+}
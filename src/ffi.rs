@@ -0,0 +1,88 @@
+//! C interface, built as a static or dynamic library with e.g.
+//! `cargo rustc --release --features ffi --crate-type staticlib`.
+//!
+//! A holiday kind crosses the boundary as its declaration index in [`HolidayKind`], e.g. `0` for
+//! [`HolidayKind::RegularHoliday`] and `4` for [`HolidayKind::L0101Holiday`].
+
+use core::ptr;
+
+use crate::{HolidayKind, KINDS};
+
+/// Error returned for a kind without a festival, or out of range.
+pub const CMH_ERROR_KIND: i32 = -1;
+/// Error returned for a null buffer of non-zero length.
+pub const CMH_ERROR_NULL: i32 = -2;
+
+/// Copies the UTF-8 Chinese name of the festival of the kind into `buf`, e.g. `春节`, and returns
+/// the length of the whole name in bytes, excluding any terminating NUL, which is not written.
+///
+/// As with `snprintf`, nothing is written when `len` is less than the returned length, so a name
+/// is never truncated, not even between characters. Pass a null `buf` with `len` 0 to query the
+/// length.
+///
+/// Returns [`CMH_ERROR_KIND`] when the kind has no festival or is out of range, and
+/// [`CMH_ERROR_NULL`] when `buf` is null but `len` is not 0.
+///
+/// # Safety
+///
+/// `buf` must be null or valid for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn cmh_festival_name_zh(kind: u8, buf: *mut u8, len: usize) -> i32 {
+    let Some(festival) = KINDS.get(kind as usize).and_then(HolidayKind::festival) else {
+        return CMH_ERROR_KIND;
+    };
+    if buf.is_null() && len != 0 {
+        return CMH_ERROR_NULL;
+    }
+    let name = festival.name_zh();
+    if name.len() <= len {
+        // SAFETY: `buf` is valid for writes of `len` bytes, and `name.len() <= len`.
+        unsafe { ptr::copy_nonoverlapping(name.as_ptr(), buf, name.len()) };
+    }
+    name.len() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kinds() {
+        for (i, kind) in KINDS.into_iter().enumerate() {
+            assert_eq!(kind as usize, i);
+        }
+    }
+
+    #[test]
+    fn test_festival_name_zh() {
+        let mut buf = [0u8; 16];
+        let n = unsafe { cmh_festival_name_zh(4, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(n, 6);
+        assert_eq!(&buf[..6], "春节".as_bytes());
+        assert_eq!(buf[6], 0);
+
+        let mut buf = [0u8; 6];
+        let n = unsafe { cmh_festival_name_zh(5, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(n, 6);
+        assert_eq!(core::str::from_utf8(&buf), Ok("春节"));
+
+        let mut buf = [0u8; 5];
+        let n = unsafe { cmh_festival_name_zh(5, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(n, 6);
+        assert_eq!(buf, [0; 5]);
+
+        let mut buf = [0u8; 2];
+        let n = unsafe { cmh_festival_name_zh(4, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(n, 6);
+        assert_eq!(buf, [0, 0]);
+
+        let n = unsafe { cmh_festival_name_zh(14, ptr::null_mut(), 0) };
+        assert_eq!(n, "国庆节".len() as i32);
+        let n = unsafe { cmh_festival_name_zh(14, ptr::null_mut(), 1) };
+        assert_eq!(n, CMH_ERROR_NULL);
+        let n = unsafe { cmh_festival_name_zh(0, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(n, CMH_ERROR_KIND);
+        let n = unsafe { cmh_festival_name_zh(16, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(n, CMH_ERROR_KIND);
+    }
+}
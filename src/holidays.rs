@@ -7,6 +7,18 @@ use crate::{
 pub const MIN_YEAR: u16 = 2024;
 /// Maximum year of which holidays are recorded.
 pub const MAX_YEAR: u16 = 2024;
+/// First day of [`MIN_YEAR`].
+pub const MIN_DATE: HolidayDate = HolidayDate {
+    year: MIN_YEAR,
+    month: 1,
+    day: 1,
+};
+/// Last day of [`MAX_YEAR`].
+pub const MAX_DATE: HolidayDate = HolidayDate {
+    year: MAX_YEAR,
+    month: 12,
+    day: 31,
+};
 
 macro_rules! record {
     ($y:literal $m:literal $d:literal $kind:ident) => {
@@ -22,6 +34,25 @@ macro_rules! record {
     };
 }
 
+/// The first day of the first lunar month of each year from [`MIN_YEAR`] to [`MAX_YEAR`].
+pub(crate) const SPRING_FESTIVALS: [HolidayDate; (MAX_YEAR - MIN_YEAR + 1) as usize] =
+    [HolidayDate {
+        year: 2024,
+        month: 2,
+        day: 10,
+    }];
+
+/// Official notices each covering the records from the given key until the next notice.
+pub(crate) const SOURCES: [(u32, &str); 1] = [(
+    HolidayDate {
+        year: 2024,
+        month: 1,
+        day: 1,
+    }
+    .u32_value(),
+    "https://www.gov.cn/zhengce/zhengceku/202310/content_6911528.htm",
+)];
+
 pub(crate) const HOLIDAYS: [(u32, HolidayKind); 27] = [
     // https://www.gov.cn/zhengce/zhengceku/202310/content_6911528.htm
     record!(2024 1 1 G0101Holiday),
@@ -53,22 +84,169 @@ pub(crate) const HOLIDAYS: [(u32, HolidayKind); 27] = [
     record!(2024 10 12 G1001Workday),
 ];
 
+macro_rules! swap {
+    ($y1:literal $m1:literal $d1:literal, $y2:literal $m2:literal $d2:literal) => {
+        (
+            HolidayDate {
+                year: $y1,
+                month: $m1,
+                day: $d1,
+            }
+            .u32_value(),
+            HolidayDate {
+                year: $y2,
+                month: $m2,
+                day: $d2,
+            }
+            .u32_value(),
+        )
+    };
+}
+
+/// Adjusted working days and the weekday holidays they are swapped with, in ascending order.
+///
+/// Weekday holidays that are statutory, or compensate a statutory holiday on Saturday or Sunday,
+/// have no swap. The notices do not pair the rest explicitly, so they are paired in chronological
+/// order within each festival.
+pub(crate) const SWAPS: [(u32, u32); 8] = [
+    swap!(2024 2 4, 2024 2 15),
+    swap!(2024 2 18, 2024 2 16),
+    swap!(2024 4 7, 2024 4 5),
+    swap!(2024 4 28, 2024 5 2),
+    swap!(2024 5 11, 2024 5 3),
+    swap!(2024 9 14, 2024 9 16),
+    swap!(2024 9 29, 2024 10 4),
+    swap!(2024 10 12, 2024 10 7),
+];
+
+macro_rules! key {
+    ($y:literal $m:literal $d:literal) => {
+        HolidayDate {
+            year: $y,
+            month: $m,
+            day: $d,
+        }
+        .u32_value()
+    };
+}
+
+/// Statutory holidays in ascending order, whatever the day of week, as designated by the
+/// Regulations on Public Holidays for National Annual Festivals and Memorial Days (全国年节及纪念日
+/// 放假办法) of 2013: New Year's Day, the first three days of the first lunar month, Qingming,
+/// May 1, the Dragon Boat Festival, the Mid-Autumn Festival and October 1 to 3.
+pub(crate) const STATUTORY: [u32; 11] = [
+    key!(2024 1 1),
+    key!(2024 2 10),
+    key!(2024 2 11),
+    key!(2024 2 12),
+    key!(2024 4 4),
+    key!(2024 5 1),
+    key!(2024 6 10),
+    key!(2024 9 17),
+    key!(2024 10 1),
+    key!(2024 10 2),
+    key!(2024 10 3),
+];
+
+// Lookups binary search the table, so fail compilation unless it is strictly ascending.
+const _: () = {
+    let mut i = 1;
+    while i < HOLIDAYS.len() {
+        assert!(
+            HOLIDAYS[i - 1].0 < HOLIDAYS[i].0,
+            "HOLIDAYS must be sorted by date without duplicates"
+        );
+        i += 1;
+    }
+};
+
+/// Returns a 64-bit FNV-1a hash of the recorded dates and kinds, to pin the built-in data.
+///
+/// The hash is stable across platforms and changes whenever any record is added, removed or
+/// edited, so downstream users can assert the data they were tested against.
+pub const fn data_fingerprint() -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let mut i = 0;
+    while i < HOLIDAYS.len() {
+        let (value, kind) = HOLIDAYS[i];
+        let value = value.to_le_bytes();
+        let mut j = 0;
+        while j < value.len() {
+            hash = (hash ^ value[j] as u64).wrapping_mul(0x0100_0000_01b3);
+            j += 1;
+        }
+        hash = (hash ^ kind as u64).wrapping_mul(0x0100_0000_01b3);
+        i += 1;
+    }
+    hash
+}
+
+/// Returns the recorded dates and kinds in ascending order of date.
+#[cfg(feature = "alloc")]
+pub(crate) fn records() -> impl DoubleEndedIterator<Item = (HolidayDate, HolidayKind)> {
+    HOLIDAYS
+        .iter()
+        .map(|(v, kind)| (HolidayDate::from_u32_value(*v), *kind))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
+    fn test_data_fingerprint() {
+        // Update deliberately together with any change to the records.
+        assert_eq!(data_fingerprint(), 0x65a6_9dd5_a721_e2e7);
+    }
+
+    #[test]
+    #[allow(clippy::assertions_on_constants)]
     fn test_holidays() {
         assert!(MIN_YEAR > 0 && MIN_YEAR <= MAX_YEAR);
         assert!(!HOLIDAYS.is_empty());
         let first_value = HOLIDAYS.first().unwrap().0;
-        assert!(MIN_YEAR as u32 * 366 < first_value && first_value < (MIN_YEAR + 1) as u32 * 366);
+        assert!((MIN_YEAR as u32) << 9 < first_value && first_value < ((MIN_YEAR + 1) as u32) << 9);
         let last_value = HOLIDAYS.last().unwrap().0;
-        assert!(MAX_YEAR as u32 * 366 < last_value && last_value < (MAX_YEAR + 1) as u32 * 366);
+        assert!((MAX_YEAR as u32) << 9 < last_value && last_value < ((MAX_YEAR + 1) as u32) << 9);
         let mut prev = 0;
         for (v, _) in HOLIDAYS {
             assert!(prev < v);
             prev = v;
         }
+        assert!(SOURCES[0].0 <= HOLIDAYS[0].0);
+        assert!(SWAPS.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(STATUTORY.windows(2).all(|w| w[0] < w[1]));
+        for v in STATUTORY {
+            let date = HolidayDate::from_u32_value(v);
+            match HOLIDAYS.iter().find(|(key, _)| *key == v) {
+                Some((_, kind)) => assert!(kind.is_festival_holiday(), "{date}"),
+                None => assert!(date.is_weekend(), "{date}"),
+            }
+            assert!(SWAPS.iter().all(|(_, holiday)| *holiday != v), "{date}");
+        }
+        let kind = |v| {
+            HOLIDAYS
+                .iter()
+                .find(|(key, _)| *key == v)
+                .map(|(_, kind)| *kind)
+        };
+        for (workday, holiday) in SWAPS {
+            let (workday, holiday) = (kind(workday).unwrap(), kind(holiday).unwrap());
+            assert!(!workday.is_day_off() && holiday.is_day_off());
+            assert_eq!(workday.festival(), holiday.festival());
+        }
+        for (v, kind) in HOLIDAYS {
+            if !kind.is_day_off() {
+                assert_eq!(SWAPS.iter().filter(|(w, _)| *w == v).count(), 1);
+            }
+        }
+        for (year, date) in (MIN_YEAR..).zip(SPRING_FESTIVALS) {
+            assert_eq!(date.year, year);
+        }
+        assert!(SOURCES.windows(2).all(|w| w[0].0 < w[1].0));
+        #[cfg(feature = "alloc")]
+        for (date, _) in records() {
+            assert!(HolidayDate::from_ymd(date.year, date.month, date.day).is_some());
+        }
     }
 }
@@ -0,0 +1,109 @@
+//! Hong Kong general holidays.
+//!
+//! Hong Kong observes a different set of holidays from Chinese Mainland, without adjusted working
+//! days. Every Sunday is a general holiday, and Saturdays are not.
+//!
+//! ```
+//! use chinese_mainland_holidays::{hongkong, HolidayDate};
+//!
+//! let date = HolidayDate::from_ymd(2024, 12, 25).unwrap();
+//! assert_eq!(hongkong::is_holiday(date), Some(true));
+//! assert_eq!(hongkong::holiday_name(date), Some("Christmas Day"));
+//! ```
+
+use crate::HolidayDate;
+
+/// Minimum year of which Hong Kong holidays are recorded.
+pub const MIN_YEAR: u16 = 2024;
+/// Maximum year of which Hong Kong holidays are recorded.
+pub const MAX_YEAR: u16 = 2024;
+
+macro_rules! record {
+    ($y:literal $m:literal $d:literal $name:literal) => {
+        (
+            HolidayDate {
+                year: $y,
+                month: $m,
+                day: $d,
+            },
+            $name,
+        )
+    };
+}
+
+const HOLIDAYS: [(HolidayDate, &str); 17] = [
+    // https://www.gov.hk/en/about/abouthk/holiday/2024.htm
+    record!(2024 1 1 "The first day of January"),
+    record!(2024 2 10 "Lunar New Year's Day"),
+    record!(2024 2 12 "The third day of Lunar New Year"),
+    record!(2024 2 13 "The fourth day of Lunar New Year"),
+    record!(2024 3 29 "Good Friday"),
+    record!(2024 3 30 "The day following Good Friday"),
+    record!(2024 4 1 "Easter Monday"),
+    record!(2024 4 4 "Ching Ming Festival"),
+    record!(2024 5 1 "Labour Day"),
+    record!(2024 5 15 "The Birthday of the Buddha"),
+    record!(2024 6 10 "Tuen Ng Festival"),
+    record!(2024 7 1 "Hong Kong Special Administrative Region Establishment Day"),
+    record!(2024 9 18 "The day following the Chinese Mid-Autumn Festival"),
+    record!(2024 10 1 "National Day"),
+    record!(2024 10 11 "Chung Yeung Festival"),
+    record!(2024 12 25 "Christmas Day"),
+    record!(2024 12 26 "The first weekday after Christmas Day"),
+];
+
+/// Returns the name of the general holiday other than Sunday on the date.
+///
+/// # Errors
+///
+/// Returns `None` when the date is not such a holiday, or the year is less than [`MIN_YEAR`] or
+/// greater than [`MAX_YEAR`].
+pub fn holiday_name(date: HolidayDate) -> Option<&'static str> {
+    HOLIDAYS
+        .binary_search_by_key(&date, |(d, _)| *d)
+        .ok()
+        .map(|i| HOLIDAYS[i].1)
+}
+
+/// Returns whether the date is a general holiday, including every Sunday.
+///
+/// # Errors
+///
+/// Returns `None` when the year is less than [`MIN_YEAR`] or greater than [`MAX_YEAR`].
+pub fn is_holiday(date: HolidayDate) -> Option<bool> {
+    if date.year < MIN_YEAR || date.year > MAX_YEAR {
+        None
+    } else {
+        Some(date.num_days_from_sunday() == 0 || holiday_name(date).is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: u16, month: u8, day: u8) -> HolidayDate {
+        HolidayDate::from_ymd(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_holidays() {
+        assert!(HOLIDAYS.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(HOLIDAYS
+            .iter()
+            .all(|(d, _)| (MIN_YEAR..=MAX_YEAR).contains(&d.year)));
+    }
+
+    #[test]
+    fn test_is_holiday() {
+        assert_eq!(is_holiday(date(2024, 12, 25)), Some(true));
+        assert_eq!(is_holiday(date(2024, 3, 29)), Some(true));
+        assert_eq!(is_holiday(date(2024, 5, 15)), Some(true));
+        assert_eq!(is_holiday(date(2024, 10, 6)), Some(true));
+        assert_eq!(is_holiday(date(2024, 10, 5)), Some(false));
+        assert_eq!(is_holiday(date(2024, 10, 2)), Some(false));
+        assert_eq!(is_holiday(date(2023, 12, 25)), None);
+        assert_eq!(holiday_name(date(2024, 4, 1)), Some("Easter Monday"));
+        assert_eq!(holiday_name(date(2024, 10, 6)), None);
+    }
+}
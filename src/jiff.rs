@@ -0,0 +1,114 @@
+use jiff::{civil::Date, tz, Timestamp, Zoned};
+
+use crate::{HolidayDate, HolidayError, HolidayKind, HolidayLike};
+
+impl From<Date> for HolidayDate {
+    #[inline]
+    fn from(value: Date) -> Self {
+        Self {
+            year: value.year() as u16,
+            month: value.month() as u8,
+            day: value.day() as u8,
+        }
+    }
+}
+
+impl From<&Zoned> for HolidayDate {
+    /// Normalizes to China Standard Time, UTC+8, which `Asia/Shanghai` has observed without
+    /// daylight saving time since 1992.
+    #[inline]
+    fn from(value: &Zoned) -> Self {
+        cst_date(value.timestamp()).into()
+    }
+}
+
+impl From<Zoned> for HolidayDate {
+    #[inline]
+    fn from(value: Zoned) -> Self {
+        (&value).into()
+    }
+}
+
+impl TryFrom<HolidayDate> for Date {
+    type Error = HolidayError;
+
+    /// # Errors
+    ///
+    /// Returns [`HolidayError::InvalidDate`] when the date is out of the range of `Date`.
+    #[inline]
+    fn try_from(value: HolidayDate) -> Result<Self, Self::Error> {
+        let year = i16::try_from(value.year).map_err(|_| HolidayError::InvalidDate)?;
+        Date::new(year, value.month as i8, value.day as i8).map_err(|_| HolidayError::InvalidDate)
+    }
+}
+
+/// Returns the civil date of the instant in China Standard Time.
+fn cst_date(timestamp: Timestamp) -> Date {
+    timestamp
+        .to_zoned(tz::TimeZone::fixed(tz::offset(8)))
+        .date()
+}
+
+impl HolidayLike for Date {
+    #[inline]
+    fn holiday_kind(&self) -> Option<HolidayKind> {
+        HolidayDate::from(*self).holiday_kind()
+    }
+}
+
+impl HolidayLike for Zoned {
+    #[inline]
+    fn holiday_kind(&self) -> Option<HolidayKind> {
+        HolidayDate::from(self).holiday_kind()
+    }
+
+    #[inline]
+    fn holiday_kind_utc(&self) -> Option<HolidayKind> {
+        HolidayDate::from(self.timestamp().to_zoned(tz::TimeZone::UTC).date()).holiday_kind()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(s: &str) -> Zoned {
+        s.parse::<Timestamp>().unwrap().to_zoned(tz::TimeZone::UTC)
+    }
+
+    #[test]
+    fn test_zoned_to_holiday() {
+        let date: HolidayDate = utc("2024-10-01T20:00:00Z").into();
+        assert_eq!(date, HolidayDate::from_ymd(2024, 10, 2).unwrap());
+    }
+
+    #[test]
+    fn test_date_round_trip() {
+        let civil = Date::new(2024, 10, 1).unwrap();
+        let date = HolidayDate::from(civil);
+        assert_eq!(date, HolidayDate::from_ymd(2024, 10, 1).unwrap());
+        assert_eq!(Date::try_from(date).unwrap(), civil);
+        let date = HolidayDate::from_ymd(10000, 1, 1).unwrap();
+        assert!(matches!(
+            Date::try_from(date),
+            Err(HolidayError::InvalidDate)
+        ));
+    }
+
+    #[test]
+    fn test_holiday_kind_utc() {
+        let zoned = utc("2024-09-30T20:00:00Z");
+        assert!(matches!(
+            zoned.holiday_kind(),
+            Some(HolidayKind::G1001Holiday)
+        ));
+        assert!(matches!(
+            zoned.holiday_kind_utc(),
+            Some(HolidayKind::RegularWorkday)
+        ));
+        assert!(matches!(
+            zoned.date().holiday_kind(),
+            Some(HolidayKind::RegularWorkday)
+        ));
+    }
+}
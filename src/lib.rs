@@ -13,14 +13,106 @@
 //!
 //! Default features:
 //!
-//! - `chrono`: Implements [`HolidayLike`] for `chrono` date and datetime types.
+//! - `chrono`: Implements [`HolidayLike`] for `chrono` date and datetime types, and enables
+//!   `holidays_in_year_naive` together with `alloc`.
+//! - `names`: Enables English and Chinese festival names, such as [`Festival::name`],
+//!   `HolidayDate::observance_note` and `HolidayDate::describe_localized`. Disabling it for
+//!   size-constrained targets drops about 1 KiB of string data; lookups such as
+//!   [`HolidayLike::is_holiday`] stay available.
+//! - `std`: Enables [`HolidayCalendar::load_from_csv`], [`active_table`] and implements
+//!   [`std::error::Error`]. Implies `alloc`.
+//!
+//! Optional features:
+//!
+//! - `cli`: Builds the `holiday` binary, e.g. `holiday 2024-10-01` prints `holiday (National Day)`
+//!   and exits with 0, or 1 for a working day. Implies `names` and `std`.
+//! - `ffi`: Enables the `ffi` module of C functions. Implies `names`.
+//! - `hongkong`: Enables the `hongkong` module of Hong Kong general holidays.
+//! - `jiff`: Implements [`HolidayLike`] for `jiff::civil::Date` and `jiff::Zoned`.
+//! - `alloc`: Enables [`HolidayCalendar`], [`HolidayTable`] and methods returning `Vec` without
+//!   `std`.
+//! - `serde`: Implements `Serialize` and `Deserialize` for [`HolidayDate`], [`HolidayKind`] and
+//!   `HolidayCalendar`. Implies `alloc`.
+//! - `test-util`: Enables the `test_util` module of assertions for downstream tests.
+//! - `time`: Implements [`HolidayLike`] for `time::Date`, with a `const` conversion for dates
+//!   built by `time::macros::date!`.
+//! - `toml`: Enables [`HolidayCalendar::from_toml`]. Implies `std`.
+//!
+#![cfg_attr(
+    not(feature = "toml"),
+    doc = "[`HolidayCalendar::from_toml`]: HolidayCalendar"
+)]
+#![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+mod active;
+#[cfg(feature = "alloc")]
+mod calendar;
 #[cfg(feature = "chrono")]
 mod chrono;
+mod describe;
+mod error;
+mod festival;
+#[cfg(feature = "ffi")]
+pub mod ffi;
 mod holidays;
+#[cfg(feature = "hongkong")]
+pub mod hongkong;
+mod index;
+#[cfg(feature = "jiff")]
+mod jiff;
+mod periods;
+mod ranges;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "alloc")]
+mod table;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+#[cfg(feature = "time")]
+mod time;
+mod unix;
+mod workweek;
+
+use core::{fmt, str::FromStr};
 
-use holidays::HOLIDAYS;
-pub use holidays::{MAX_YEAR, MIN_YEAR};
+#[cfg(all(feature = "chrono", feature = "alloc"))]
+pub use self::chrono::holidays_in_year_naive;
+#[cfg(feature = "std")]
+pub use active::{active_table, set_active_table};
+#[cfg(feature = "alloc")]
+pub use calendar::{apply_patch, HolidayCalendar, MergeStrategy};
+pub use describe::Lang;
+#[cfg(feature = "alloc")]
+pub use describe::{format_period, format_period_zh, print_year, text_calendar};
+pub use error::HolidayError;
+#[cfg(feature = "alloc")]
+pub use festival::compensation_pairs;
+pub use festival::{
+    observed_break_length, spring_festival_day1, statutory_days, year_data_complete, Festival,
+};
+pub use holidays::{data_fingerprint, MAX_DATE, MAX_YEAR, MIN_DATE, MIN_YEAR};
+use holidays::{HOLIDAYS, SOURCES, STATUTORY, SWAPS};
+pub use index::{is_day_off_2024, is_holiday_of, YearWorkdayIndex, YEAR_2024_DAYOFF};
+pub use periods::new_year_span;
+pub use ranges::{
+    contains_holiday, first_holiday_of_year, last_holiday_of_year, makeup_workdays_between,
+    net_extra_rest_days, range_summary, rest_ratio, statutory_impact, transitions_in_year,
+    working_hours_between, year_calendar, HolidayDateIteratorExt, HolidayDateRange, RangeSummary,
+    StatutoryImpact,
+};
+#[cfg(feature = "alloc")]
+pub use ranges::{
+    filter_year, holidays_in_iso_week, kind_histogram, long_weekends, makeup_workdays_in_month,
+    year_map,
+};
+#[cfg(feature = "alloc")]
+pub use table::HolidayTable;
+pub use unix::{holiday_kind_unix, is_holiday_unix};
+pub use workweek::{PolicyKind, WeekendPolicy, WorkweekConfig};
 
 /// The type of a holiday or working day.
 ///
@@ -28,7 +120,7 @@ pub use holidays::{MAX_YEAR, MIN_YEAR};
 /// Each `*Workday` is Saturday or Sunday but a working day.
 ///
 /// This enum is marked non_exhaustive to accomodate newly established holidays.
-#[derive(Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum HolidayKind {
     /// A regular Saturday or Sunday.
@@ -65,9 +157,35 @@ pub enum HolidayKind {
     G1001Workday,
 }
 
+/// Every holiday kind in declaration order.
+const KINDS: [HolidayKind; 16] = {
+    use HolidayKind::*;
+    [
+        RegularHoliday,
+        RegularWorkday,
+        G0101Holiday,
+        G0101Workday,
+        L0101Holiday,
+        L0101Workday,
+        S05Holiday,
+        S05Workday,
+        G0501Holiday,
+        G0501Workday,
+        L0505Holiday,
+        L0505Workday,
+        L0815Holiday,
+        L0815Workday,
+        G1001Holiday,
+        G1001Workday,
+    ]
+};
+
 /// Utility type for looking up holiday info.
+///
+/// Dates are ordered chronologically.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct HolidayDate {
+    // The derived `Ord` is chronological only in this field order.
     year: u16,
     month: u8,
     day: u8,
@@ -79,60 +197,510 @@ impl HolidayDate {
     /// # Errors
     ///
     /// Returns `None` when the given date is invalid or the year is less than 1.
-    pub fn from_ymd(year: u16, month: u8, day: u8) -> Option<Self> {
+    pub const fn from_ymd(year: u16, month: u8, day: u8) -> Option<Self> {
         if year == 0 {
             return None;
         }
-        if day == 0 {
-            return None;
+        match days_in_month(year, month) {
+            Some(len) if day != 0 && day <= len => {}
+            _ => return None,
         }
-        match month {
-            1 | 3 | 5 | 7 | 8 | 10 | 12 => {
-                if day > 31 {
-                    return None;
-                }
-            }
-            4 | 6 | 9 | 11 => {
-                if day > 30 {
-                    return None;
-                }
-            }
-            2 => {
-                if day > 28 && !(day == 29 && (year % 4 == 0 && year % 100 != 0 || year % 400 == 0))
-                {
-                    return None;
-                }
+        Some(Self { year, month, day })
+    }
+
+    /// Constructs from year, month and day given as const generic parameters, validated at compile
+    /// time.
+    ///
+    /// ```
+    /// use chinese_mainland_holidays::{holiday_kind_of, HolidayDate, HolidayKind};
+    ///
+    /// const KIND: Option<HolidayKind> = holiday_kind_of(HolidayDate::const_from::<2024, 10, 1>());
+    /// assert_eq!(KIND, Some(HolidayKind::G1001Holiday));
+    /// ```
+    ///
+    /// An invalid date fails to compile:
+    ///
+    /// ```compile_fail
+    /// use chinese_mainland_holidays::HolidayDate;
+    ///
+    /// let date = HolidayDate::const_from::<2023, 2, 29>();
+    /// ```
+    pub const fn const_from<const Y: u16, const M: u8, const D: u8>() -> Self {
+        const {
+            match Self::from_ymd(Y, M, D) {
+                Some(date) => date,
+                None => panic!("invalid date"),
             }
-            _ => {
-                return None;
+        }
+    }
+
+    /// Constructs from year and 1-based day of year.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when the day of year is out of range or the year is less than 1.
+    pub fn from_yo(year: u16, ordinal: u16) -> Option<Self> {
+        if year == 0 || ordinal == 0 {
+            return None;
+        }
+        let mut day = ordinal;
+        for month in 1..=12 {
+            let len = days_in_month(year, month)? as u16;
+            if day <= len {
+                return Some(Self {
+                    year,
+                    month,
+                    day: day as u8,
+                });
             }
+            day -= len;
         }
-        Some(Self { year, month, day })
+        None
+    }
+
+    /// Returns the 1-based day of year.
+    pub fn ordinal(&self) -> u16 {
+        (1..self.month)
+            .filter_map(|month| days_in_month(self.year, month))
+            .map(u16::from)
+            .sum::<u16>()
+            + self.day as u16
+    }
+
+    /// Returns the 1-based day of year, accounting for leap years, e.g. 60 for February 29.
+    ///
+    /// Same as [`ordinal`](Self::ordinal), but checks the date first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when the date was constructed internally without validation and does not
+    /// exist.
+    pub fn day_of_year(&self) -> Option<u16> {
+        Self::from_ymd(self.year, self.month, self.day)?;
+        Some(self.ordinal())
+    }
+
+    /// Returns the ISO 8601 week-numbering year and week of the date, where week 1 is the week
+    /// containing the first Thursday of the year.
+    ///
+    /// The first days of a year may belong to the last week of the previous year, and the last
+    /// days to week 1 of the next year. The ISO year saturates at 65535.
+    pub fn iso_week(&self) -> (u16, u8) {
+        let weekday = self.num_days_from_monday() + 1;
+        let week = (self.ordinal() as i32 - weekday as i32 + 10) / 7;
+        if week < 1 {
+            (self.year - 1, iso_weeks_in_year(self.year - 1))
+        } else if week > iso_weeks_in_year(self.year) as i32 {
+            (self.year.saturating_add(1), 1)
+        } else {
+            (self.year, week as u8)
+        }
+    }
+
+    /// Returns the date `days` away, which may be negative.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when the result is before 0001-01-01 or after 65535-12-31.
+    pub fn checked_add_days(&self, days: i32) -> Option<Self> {
+        Self::from_day_number(self.day_number().checked_add(days)?)
+    }
+
+    /// Returns the holiday kind of the date `days` away, which may be negative, e.g. the kind of
+    /// tomorrow with `1`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when the shifted date is less than [`MIN_DATE`] or greater than
+    /// [`MAX_DATE`].
+    pub fn kind_offset(&self, days: i32) -> Option<HolidayKind> {
+        self.checked_add_days(days)?.holiday_kind()
+    }
+
+    /// Returns the number of days since 0001-01-01.
+    pub(crate) fn day_number(&self) -> i32 {
+        let y = self.year as i32 - 1;
+        y * 365 + y / 4 - y / 100 + y / 400 + self.ordinal() as i32 - 1
+    }
+
+    /// Inverse of [`day_number`](Self::day_number), or `None` when the year is out of range.
+    pub(crate) fn from_day_number(n: i32) -> Option<Self> {
+        // Adapted from <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>,
+        // counting from 0000-03-01 so that leap days end each cycle.
+        let z = n.checked_add(306)?;
+        let era = z.div_euclid(146097);
+        let doe = z.rem_euclid(146097);
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+        let year = era * 400 + yoe + (month <= 2) as i32;
+        Self::from_ymd(year.try_into().ok()?, month, day)
+    }
+
+    /// Returns the next calendar day, or `None` on year overflow.
+    pub(crate) fn succ(&self) -> Option<Self> {
+        if self.day < days_in_month(self.year, self.month)? {
+            Some(Self {
+                day: self.day + 1,
+                ..*self
+            })
+        } else if self.month < 12 {
+            Some(Self {
+                year: self.year,
+                month: self.month + 1,
+                day: 1,
+            })
+        } else {
+            Some(Self {
+                year: self.year.checked_add(1)?,
+                month: 1,
+                day: 1,
+            })
+        }
+    }
+
+    /// Returns the previous calendar day, or `None` before year 1.
+    pub(crate) fn pred(&self) -> Option<Self> {
+        if self.day > 1 {
+            Some(Self {
+                day: self.day - 1,
+                ..*self
+            })
+        } else if self.month > 1 {
+            Some(Self {
+                year: self.year,
+                month: self.month - 1,
+                day: days_in_month(self.year, self.month - 1)?,
+            })
+        } else if self.year > 1 {
+            Some(Self {
+                year: self.year - 1,
+                month: 12,
+                day: 31,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the holiday kind of the date, falling back to [`HolidayKind::RegularHoliday`] for
+    /// Saturday and Sunday and [`HolidayKind::RegularWorkday`] otherwise.
+    ///
+    /// The result is exact from [`MIN_YEAR`] to [`MAX_YEAR`], and only approximate outside that
+    /// range, where festival holidays and adjusted working days are unknown.
+    pub fn holiday_kind_approx(&self) -> HolidayKind {
+        self.holiday_kind().unwrap_or_else(|| self.regular_kind())
+    }
+
+    /// Returns the URL of the official notice of the recorded festival holiday or adjusted working
+    /// day.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when the date is not recorded, such as a regular Saturday, Sunday or
+    /// weekday.
+    pub fn holiday_source(&self) -> Option<&'static str> {
+        if !is_recorded(*self) {
+            return None;
+        }
+        let key = self.u32_value();
+        let i = SOURCES.partition_point(|(first, _)| *first <= key);
+        Some(SOURCES[i.checked_sub(1)?].1)
+    }
+
+    /// Returns the recorded festival holiday or adjusted working day closest to this date, which
+    /// may be this date itself. Of two equally close records, the later one is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when the year is less than [`MIN_YEAR`] or greater than [`MAX_YEAR`].
+    pub fn nearest_adjustment(&self) -> Option<(HolidayDate, HolidayKind)> {
+        if !(MIN_YEAR..=MAX_YEAR).contains(&self.year) {
+            return None;
+        }
+        let i = HOLIDAYS.partition_point(|(v, _)| *v < self.u32_value());
+        let record = |i: usize| {
+            let (v, kind) = HOLIDAYS[i];
+            (Self::from_u32_value(v), kind)
+        };
+        let distance = |date: HolidayDate| (date.day_number() - self.day_number()).abs();
+        match (
+            i.checked_sub(1).map(record),
+            HOLIDAYS.get(i).map(|_| record(i)),
+        ) {
+            (Some(prev), Some(next)) if distance(prev.0) < distance(next.0) => Some(prev),
+            (_, Some(next)) => Some(next),
+            (prev, None) => prev,
+        }
+    }
+
+    /// Returns the day of week counted from Monday as 0 to Sunday as 6.
+    pub const fn num_days_from_monday(&self) -> u8 {
+        (self.num_days_from_sunday() + 6) % 7
+    }
+
+    /// Returns the day of week counted from Sunday as 0 to Saturday as 6.
+    pub const fn num_days_from_sunday(&self) -> u8 {
+        day_of_week(self.year, self.month, self.day)
     }
 
+    /// Returns whether the date is Saturday or Sunday, regardless of any adjusted working day.
+    pub const fn is_weekend(&self) -> bool {
+        matches!(self.num_days_from_sunday(), 0 | 6)
+    }
+
+    /// Returns whether the date is Saturday or Sunday but an adjusted working day.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when the year is less than [`MIN_YEAR`] or greater than [`MAX_YEAR`].
+    pub fn is_worked_weekend(&self) -> Option<bool> {
+        Some(self.is_weekend() && !self.is_holiday()?)
+    }
+
+    /// Returns whether the date is Monday to Friday but a festival holiday.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when the year is less than [`MIN_YEAR`] or greater than [`MAX_YEAR`].
+    pub fn is_weekday_holiday(&self) -> Option<bool> {
+        Some(!self.is_weekend() && self.is_holiday()?)
+    }
+
+    /// Returns whether the date is a festival holiday, i.e. one of the `*Holiday` kinds, as
+    /// opposed to a regular Saturday or Sunday off.
+    ///
+    /// This includes weekdays swapped with an adjusted working day, as counted per festival by
+    /// [`statutory_days`]. See [`is_triple_pay_day`](Self::is_triple_pay_day) for the statutory
+    /// holidays themselves.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when the year is less than [`MIN_YEAR`] or greater than [`MAX_YEAR`].
+    pub fn is_statutory_holiday(&self) -> Option<bool> {
+        Some(self.holiday_kind()?.is_festival_holiday())
+    }
+
+    /// Returns whether the date is a statutory holiday itself, paid at 300% when worked under
+    /// Article 44 of the Labour Law, as opposed to the other days off of its break, which are paid
+    /// at 200% when worked unless compensated with another day off.
+    ///
+    /// The statutory holidays are New Year's Day, the first three days of the first lunar month,
+    /// Qingming, May 1, the Dragon Boat Festival, the Mid-Autumn Festival and October 1 to 3.
+    /// They count even on Saturday or Sunday, e.g. 2024-02-10, while the weekdays compensating
+    /// them, e.g. 2024-02-13, and the weekdays swapped with an adjusted working day, e.g.
+    /// 2024-10-04, do not.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when the year is less than [`MIN_YEAR`] or greater than [`MAX_YEAR`].
+    pub fn is_triple_pay_day(&self) -> Option<bool> {
+        self.holiday_kind()?;
+        Some(STATUTORY.contains(&self.u32_value()))
+    }
+
+    /// Returns the pay multiplier for working a full day on the date, following Article 44 of the
+    /// Labour Law: `3.0` on a statutory holiday, `2.0` on any other day off, and `1.0` on a working
+    /// day.
+    ///
+    /// This assumes an adjusted working day is paid as a regular working day, and a rest day is not
+    /// compensated with another day off, which would reduce `2.0` to `1.0`. Extended hours on a
+    /// working day, paid at `1.5`, are not considered.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when the year is less than [`MIN_YEAR`] or greater than [`MAX_YEAR`].
+    pub fn pay_multiplier(&self) -> Option<f32> {
+        if self.is_triple_pay_day()? {
+            Some(3.0)
+        } else if self.is_holiday()? {
+            Some(2.0)
+        } else {
+            Some(1.0)
+        }
+    }
+
+    /// Writes the date as `YYYY-MM-DD`, like [`Display`](fmt::Display) but into any writer, such
+    /// as a fixed buffer without allocation.
+    pub fn write_iso<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        write!(w, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+
+    /// Returns the kind of the date ignoring any recorded adjustment.
+    pub(crate) const fn regular_kind(&self) -> HolidayKind {
+        if self.is_weekend() {
+            HolidayKind::RegularHoliday
+        } else {
+            HolidayKind::RegularWorkday
+        }
+    }
+
+    /// Inverse of [`u32_value`](Self::u32_value).
+    #[inline]
+    const fn from_u32_value(value: u32) -> Self {
+        Self {
+            year: (value >> 9) as u16,
+            month: ((value >> 5) & 0xf) as u8,
+            day: (value & 0x1f) as u8,
+        }
+    }
+
+    /// Returns a key ordered as the date, packing month and day into the low 9 bits.
+    ///
+    /// The bit packing keeps December of one year below January of the next, so a record in late
+    /// December for the next New Year sorts correctly.
     #[inline]
     const fn u32_value(&self) -> u32 {
-        (self.year as u32 * 366) + (self.month as u32 * 31) + self.day as u32
+        ((self.year as u32) << 9) | ((self.month as u32) << 5) | self.day as u32
+    }
+}
+
+/// Returns the number of days from `start` to `end`, negative when `end` is before `start`.
+pub fn days_between(start: HolidayDate, end: HolidayDate) -> i32 {
+    end.day_number() - start.day_number()
+}
+
+/// Returns the earliest of the dates, or `None` when there are none.
+pub fn earliest<I: IntoIterator<Item = HolidayDate>>(dates: I) -> Option<HolidayDate> {
+    dates.into_iter().min()
+}
+
+/// Returns the latest of the dates, or `None` when there are none.
+pub fn latest<I: IntoIterator<Item = HolidayDate>>(dates: I) -> Option<HolidayDate> {
+    dates.into_iter().max()
+}
+
+/// Returns whether the year is a leap year in the Gregorian calendar.
+pub const fn is_leap_year(year: u16) -> bool {
+    year.is_multiple_of(4) && !year.is_multiple_of(100) || year.is_multiple_of(400)
+}
+
+/// Returns the number of days in the month of the year.
+///
+/// # Errors
+///
+/// Returns `None` when the month is not in 1-12.
+pub const fn days_in_month(year: u16, month: u8) -> Option<u8> {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => Some(31),
+        4 | 6 | 9 | 11 => Some(30),
+        2 if is_leap_year(year) => Some(29),
+        2 => Some(28),
+        _ => None,
     }
 }
 
 /// Returns day of week represented by 0-6, where Sunday is 0.
 ///
 /// The formula is called Zeller's Congruence, adapted from <https://datatracker.ietf.org/doc/html/rfc3339#appendix-B>.
-fn day_of_week(year: u16, month: u8, day: u8) -> u8 {
+///
+/// Years are shifted by 400, a whole cycle of the Gregorian calendar keeping days of week, so that
+/// January and February of year 0 do not underflow.
+const fn day_of_week(year: u16, month: u8, day: u8) -> u8 {
     let m: u8;
-    let mut y: u16;
+    let mut y: u32;
     if month > 2 {
         m = month - 2;
-        y = year;
+        y = year as u32 + 400;
     } else {
         m = month + 10;
-        y = year - 1;
+        y = year as u32 + 399;
     }
     let c = y / 100;
     y %= 100;
     // assert!(13 * m < u8::MAX);
-    ((((13 * m - 1) / 5 + day) as u16 + y + y / 4 + c / 4 + 5 * c) % 7) as u8
+    let result = ((((13 * m - 1) / 5 + day) as u32 + y + y / 4 + c / 4 + 5 * c) % 7) as u8;
+    debug_assert!(result < 7);
+    result
+}
+
+/// Returns the number of ISO 8601 weeks in the week-numbering year, 52 or 53.
+pub(crate) const fn iso_weeks_in_year(year: u16) -> u8 {
+    match day_of_week(year, 1, 1) {
+        4 => 53,
+        3 if is_leap_year(year) => 53,
+        _ => 52,
+    }
+}
+
+impl HolidayKind {
+    /// Every kind in declaration order, e.g. for building a legend.
+    pub const ALL_KINDS: &'static [HolidayKind] = &KINDS;
+
+    /// Every kind that is a day off, i.e. [`RegularHoliday`](Self::RegularHoliday) and each
+    /// `*Holiday`, in declaration order.
+    pub const HOLIDAY_KINDS: &'static [HolidayKind] = &Self::filter_kinds::<8>(true);
+
+    /// Every kind that is a working day, i.e. [`RegularWorkday`](Self::RegularWorkday) and each
+    /// `*Workday`, in declaration order.
+    pub const WORKDAY_KINDS: &'static [HolidayKind] = &Self::filter_kinds::<8>(false);
+
+    /// Returns the `N` kinds whose [`is_day_off`](Self::is_day_off) equals `day_off`, failing
+    /// compilation unless there are exactly `N`.
+    const fn filter_kinds<const N: usize>(day_off: bool) -> [HolidayKind; N] {
+        let mut kinds = [HolidayKind::RegularHoliday; N];
+        let (mut i, mut n) = (0, 0);
+        while i < KINDS.len() {
+            if KINDS[i].is_day_off() == day_off {
+                kinds[n] = KINDS[i];
+                n += 1;
+            }
+            i += 1;
+        }
+        assert!(n == N, "wrong number of kinds");
+        kinds
+    }
+
+    /// Returns whether this kind is a day off.
+    pub(crate) const fn is_day_off(&self) -> bool {
+        use HolidayKind::*;
+        match self {
+            RegularHoliday | G0101Holiday | L0101Holiday | S05Holiday | G0501Holiday
+            | L0505Holiday | L0815Holiday | G1001Holiday => true,
+            RegularWorkday | G0101Workday | L0101Workday | S05Workday | G0501Workday
+            | L0505Workday | L0815Workday | G1001Workday => false,
+        }
+    }
+
+    /// Returns the variant name.
+    const fn name(&self) -> &'static str {
+        use HolidayKind::*;
+        match self {
+            RegularHoliday => "RegularHoliday",
+            RegularWorkday => "RegularWorkday",
+            G0101Holiday => "G0101Holiday",
+            G0101Workday => "G0101Workday",
+            L0101Holiday => "L0101Holiday",
+            L0101Workday => "L0101Workday",
+            S05Holiday => "S05Holiday",
+            S05Workday => "S05Workday",
+            G0501Holiday => "G0501Holiday",
+            G0501Workday => "G0501Workday",
+            L0505Holiday => "L0505Holiday",
+            L0505Workday => "L0505Workday",
+            L0815Holiday => "L0815Holiday",
+            L0815Workday => "L0815Workday",
+            G1001Holiday => "G1001Holiday",
+            G1001Workday => "G1001Workday",
+        }
+    }
+
+    /// Returns whether this is a holiday on a weekday for a festival.
+    pub(crate) fn is_festival_holiday(&self) -> bool {
+        use HolidayKind::*;
+        matches!(
+            self,
+            G0101Holiday
+                | L0101Holiday
+                | S05Holiday
+                | G0501Holiday
+                | L0505Holiday
+                | L0815Holiday
+                | G1001Holiday
+        )
+    }
 }
 
 /// Methods for determining whether a date is a holiday.
@@ -144,36 +712,165 @@ pub trait HolidayLike {
     /// Returns `None` when the year is less than [`MIN_YEAR`] or greater than [`MAX_YEAR`].
     fn holiday_kind(&self) -> Option<HolidayKind>;
 
+    /// Returns the holiday kind of the civil day in UTC rather than China Standard Time.
+    ///
+    /// Types without a time zone have the same civil day in both, so this defaults to
+    /// [`holiday_kind`](Self::holiday_kind).
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when the year is less than [`MIN_YEAR`] or greater than [`MAX_YEAR`].
+    fn holiday_kind_utc(&self) -> Option<HolidayKind> {
+        self.holiday_kind()
+    }
+
     /// Returns whether the date is a holiday.
     ///
     /// # Errors
     ///
     /// Returns `None` when the year is less than [`MIN_YEAR`] or greater than [`MAX_YEAR`].
     fn is_holiday(&self) -> Option<bool> {
-        use HolidayKind::*;
-        match self.holiday_kind()? {
-            RegularHoliday | G0101Holiday | L0101Holiday | S05Holiday | G0501Holiday
-            | L0505Holiday | L0815Holiday | G1001Holiday => Some(true),
-            RegularWorkday | G0101Workday | L0101Workday | S05Workday | G0501Workday
-            | L0505Workday | L0815Workday | G1001Workday => Some(false),
+        Some(self.holiday_kind()?.is_day_off())
+    }
+}
+
+/// Formats the variant name, e.g. `G1001Holiday`.
+impl fmt::Display for HolidayKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Parses the variant name, e.g. `G1001Holiday`.
+impl FromStr for HolidayKind {
+    type Err = HolidayError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        KINDS
+            .into_iter()
+            .find(|kind| kind.name() == s)
+            .ok_or(HolidayError::UnknownKind)
+    }
+}
+
+/// Compares with `(year, month, day)`.
+impl PartialEq<(u16, u8, u8)> for HolidayDate {
+    fn eq(&self, other: &(u16, u8, u8)) -> bool {
+        (self.year, self.month, self.day) == *other
+    }
+}
+
+/// Formats as `YYYY-MM-DD`.
+impl fmt::Display for HolidayDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_iso(f)
+    }
+}
+
+/// Parses `YYYY-MM-DD`.
+impl FromStr for HolidayDate {
+    type Err = HolidayError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(3, '-');
+        let (Some(year), Some(month), Some(day)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(HolidayError::InvalidDate);
+        };
+        match (year.parse(), month.parse(), day.parse()) {
+            (Ok(year), Ok(month), Ok(day)) => {
+                Self::from_ymd(year, month, day).ok_or(HolidayError::InvalidDate)
+            }
+            _ => Err(HolidayError::InvalidDate),
+        }
+    }
+}
+
+/// Returns whether the date is a recorded festival holiday or adjusted working day, as opposed to
+/// a regular Saturday, Sunday or weekday, or an unsupported date.
+pub fn is_recorded(date: HolidayDate) -> bool {
+    HOLIDAYS
+        .binary_search_by_key(&date.u32_value(), |(v, _)| *v)
+        .is_ok()
+}
+
+/// Returns the adjusted working day and the weekday holiday swapped with each other, given
+/// either of them.
+///
+/// ```
+/// use chinese_mainland_holidays::{swap_pair, HolidayDate};
+///
+/// let saturday = HolidayDate::from_ymd(2024, 10, 12).unwrap();
+/// let monday = HolidayDate::from_ymd(2024, 10, 7).unwrap();
+/// assert_eq!(swap_pair(saturday), Some((saturday, monday)));
+/// assert_eq!(swap_pair(monday), Some((saturday, monday)));
+/// ```
+///
+/// # Errors
+///
+/// Returns `None` when the date is not swapped, such as a statutory holiday or a regular day.
+pub fn swap_pair(date: HolidayDate) -> Option<(HolidayDate, HolidayDate)> {
+    let key = date.u32_value();
+    SWAPS
+        .iter()
+        .find(|(workday, holiday)| *workday == key || *holiday == key)
+        .map(|(workday, holiday)| {
+            (
+                HolidayDate::from_u32_value(*workday),
+                HolidayDate::from_u32_value(*holiday),
+            )
+        })
+}
+
+/// Returns the holiday kind of the date, usable in `const` context.
+///
+/// This is the implementation of [`HolidayLike::holiday_kind`] for [`HolidayDate`] with the
+/// built-in records.
+///
+/// # Errors
+///
+/// Returns `None` when the year is less than [`MIN_YEAR`] or greater than [`MAX_YEAR`].
+pub const fn holiday_kind_of(date: HolidayDate) -> Option<HolidayKind> {
+    // Guards against invalid dates constructed internally bypassing `from_ymd`.
+    if HolidayDate::from_ymd(date.year, date.month, date.day).is_none()
+        || date.year < MIN_YEAR
+        || date.year > MAX_YEAR
+    {
+        return None;
+    }
+    let key = date.u32_value();
+    let (mut lo, mut hi) = (0, HOLIDAYS.len());
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if HOLIDAYS[mid].0 < key {
+            lo = mid + 1;
+        } else if HOLIDAYS[mid].0 > key {
+            hi = mid;
+        } else {
+            return Some(HOLIDAYS[mid].1);
         }
     }
+    Some(date.regular_kind())
 }
 
+/// With `std`, uses the table set with `set_active_table` if any, or else the built-in records.
 impl HolidayLike for HolidayDate {
+    #[inline]
     fn holiday_kind(&self) -> Option<HolidayKind> {
-        if self.year < MIN_YEAR || self.year > MAX_YEAR {
-            None
-        } else {
-            match HOLIDAYS.binary_search_by_key(&self.u32_value(), |(v, _)| *v) {
-                Ok(i) => Some(HOLIDAYS[i].1.clone()),
-                Err(_) => match day_of_week(self.year, self.month, self.day) {
-                    0 | 6 => Some(HolidayKind::RegularHoliday),
-                    1..=5 => Some(HolidayKind::RegularWorkday),
-                    _ => unreachable!(),
-                },
-            }
+        #[cfg(feature = "std")]
+        if let Some(kind) = active::active_kind(*self) {
+            return kind;
+        }
+        holiday_kind_of(*self)
+    }
+
+    #[inline]
+    fn is_holiday(&self) -> Option<bool> {
+        #[cfg(feature = "std")]
+        if let Some(kind) = active::active_kind(*self) {
+            return Some(kind?.is_day_off());
         }
+        is_holiday_of(*self)
     }
 }
 
@@ -181,10 +878,268 @@ impl HolidayLike for HolidayDate {
 mod tests {
     use super::*;
 
+    fn date(year: u16, month: u8, day: u8) -> HolidayDate {
+        HolidayDate::from_ymd(year, month, day).unwrap()
+    }
+
+    // Fails compilation if a future field makes the types unsafe to share across threads.
+    const _: () = {
+        const fn assert_send_sync<T: Send + Sync + Copy>() {}
+        assert_send_sync::<HolidayDate>();
+        assert_send_sync::<HolidayKind>();
+    };
+
+    #[test]
+    fn test_write_iso() {
+        struct Buf {
+            bytes: [u8; 10],
+            len: usize,
+        }
+        impl fmt::Write for Buf {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                let end = self.len + s.len();
+                let dst = self.bytes.get_mut(self.len..end).ok_or(fmt::Error)?;
+                dst.copy_from_slice(s.as_bytes());
+                self.len = end;
+                Ok(())
+            }
+        }
+        let mut buf = Buf {
+            bytes: [0; 10],
+            len: 0,
+        };
+        HolidayDate::from_ymd(2024, 2, 4)
+            .unwrap()
+            .write_iso(&mut buf)
+            .unwrap();
+        assert_eq!(&buf.bytes[..buf.len], b"2024-02-04");
+        assert!(HolidayDate::from_ymd(2024, 2, 4)
+            .unwrap()
+            .write_iso(&mut buf)
+            .is_err());
+    }
+
+    #[test]
+    fn test_all_kinds() {
+        // Fails compilation when a variant is added, as a reminder to add it to `KINDS`.
+        fn index(kind: HolidayKind) -> usize {
+            use HolidayKind::*;
+            match kind {
+                RegularHoliday => 0,
+                RegularWorkday => 1,
+                G0101Holiday => 2,
+                G0101Workday => 3,
+                L0101Holiday => 4,
+                L0101Workday => 5,
+                S05Holiday => 6,
+                S05Workday => 7,
+                G0501Holiday => 8,
+                G0501Workday => 9,
+                L0505Holiday => 10,
+                L0505Workday => 11,
+                L0815Holiday => 12,
+                L0815Workday => 13,
+                G1001Holiday => 14,
+                G1001Workday => 15,
+            }
+        }
+        assert_eq!(HolidayKind::ALL_KINDS.len(), 16);
+        for (i, kind) in HolidayKind::ALL_KINDS.iter().enumerate() {
+            assert_eq!(index(*kind), i, "{kind}");
+        }
+    }
+
+    #[test]
+    fn test_kinds_by_day_off() {
+        for kind in HolidayKind::ALL_KINDS {
+            let holiday = HolidayKind::HOLIDAY_KINDS.contains(kind);
+            let workday = HolidayKind::WORKDAY_KINDS.contains(kind);
+            assert!(holiday != workday, "{kind}");
+            assert_eq!(holiday, kind.is_day_off(), "{kind}");
+        }
+        assert_eq!(
+            HolidayKind::HOLIDAY_KINDS.len() + HolidayKind::WORKDAY_KINDS.len(),
+            HolidayKind::ALL_KINDS.len()
+        );
+    }
+
+    #[test]
+    fn test_days_between() {
+        assert_eq!(days_between(date(2024, 10, 1), date(2024, 10, 7)), 6);
+        assert_eq!(days_between(date(2024, 10, 7), date(2024, 10, 1)), -6);
+        assert_eq!(days_between(date(2024, 1, 1), date(2025, 1, 1)), 366);
+    }
+
+    #[test]
+    fn test_ord() {
+        let mut d = date(2023, 1, 1);
+        while d.year < 2026 {
+            let next = d.succ().unwrap();
+            assert!(d < next, "{d}");
+            assert_eq!(d.day_number().cmp(&next.day_number()), d.cmp(&next));
+            d = next;
+        }
+        assert!(date(2024, 1, 31) < date(2024, 2, 1));
+        assert!(date(2024, 9, 30) < date(2024, 10, 1));
+        assert!(date(2023, 12, 31) < date(2024, 1, 1));
+        let dates = [
+            date(2024, 10, 1),
+            date(2024, 2, 29),
+            date(2024, 10, 31),
+            date(2024, 3, 1),
+        ];
+        assert_eq!(earliest(dates), Some(date(2024, 2, 29)));
+        assert_eq!(latest(dates), Some(date(2024, 10, 31)));
+        assert_eq!(earliest([]), None);
+        assert_eq!(latest([]), None);
+    }
+
+    #[test]
+    fn test_is_leap_year() {
+        assert!(!is_leap_year(1900));
+        assert!(is_leap_year(2000));
+        assert!(is_leap_year(2024));
+        assert!(!is_leap_year(2025));
+    }
+
+    #[test]
+    fn test_days_in_month() {
+        assert_eq!(days_in_month(1900, 2), Some(28));
+        assert_eq!(days_in_month(2000, 2), Some(29));
+        assert_eq!(days_in_month(2023, 2), Some(28));
+        assert_eq!(days_in_month(2024, 2), Some(29));
+        assert_eq!(days_in_month(2024, 4), Some(30));
+        assert_eq!(days_in_month(2024, 12), Some(31));
+        assert_eq!(days_in_month(2024, 0), None);
+        assert_eq!(days_in_month(2024, 13), None);
+    }
+
     #[test]
     fn test_day_of_week() {
         assert_eq!(day_of_week(2024, 2, 29), 4);
         assert_eq!(day_of_week(2024, 10, 1), 2);
+        assert_eq!(day_of_week(0, 1, 1), 6);
+        assert_eq!(day_of_week(0, 2, 29), 2);
+        assert_eq!(day_of_week(0, 12, 31), 0);
+        assert_eq!(day_of_week(65535, 12, 31), day_of_week(65135, 12, 31));
+        let date = HolidayDate {
+            year: 0,
+            month: 1,
+            day: 1,
+        };
+        assert_eq!(date.num_days_from_monday(), 5);
+        assert!(date.is_weekend());
+    }
+
+    #[test]
+    fn test_num_days_from() {
+        let date = HolidayDate::from_ymd(2024, 10, 1).unwrap();
+        assert_eq!(date.num_days_from_monday(), 1);
+        assert_eq!(date.num_days_from_sunday(), 2);
+        let date = HolidayDate::from_ymd(2024, 10, 6).unwrap();
+        assert_eq!(date.num_days_from_monday(), 6);
+        assert_eq!(date.num_days_from_sunday(), 0);
+        let date = HolidayDate::from_ymd(2024, 10, 7).unwrap();
+        assert_eq!(date.num_days_from_monday(), 0);
+        assert_eq!(date.num_days_from_sunday(), 1);
+    }
+
+    #[test]
+    fn test_is_weekend() {
+        let date = HolidayDate::from_ymd(2024, 10, 12).unwrap();
+        assert!(date.is_weekend());
+        assert_eq!(date.is_holiday(), Some(false));
+        assert!(HolidayDate::from_ymd(2024, 10, 13).unwrap().is_weekend());
+        assert!(!HolidayDate::from_ymd(2024, 10, 1).unwrap().is_weekend());
+    }
+
+    #[test]
+    fn test_is_worked_weekend() {
+        assert_eq!(date(2024, 10, 12).is_worked_weekend(), Some(true));
+        assert_eq!(date(2024, 2, 4).is_worked_weekend(), Some(true));
+        assert_eq!(date(2024, 2, 18).is_worked_weekend(), Some(true));
+        assert_eq!(date(2024, 10, 13).is_worked_weekend(), Some(false));
+        assert_eq!(date(2024, 10, 14).is_worked_weekend(), Some(false));
+        let date = HolidayDate::from_ymd(2023, 10, 7).unwrap();
+        assert_eq!(date.is_worked_weekend(), None);
+    }
+
+    #[test]
+    fn test_is_statutory_holiday() {
+        assert_eq!(date(2024, 10, 1).is_statutory_holiday(), Some(true));
+        assert_eq!(date(2024, 10, 7).is_statutory_holiday(), Some(true));
+        assert_eq!(date(2024, 10, 6).is_statutory_holiday(), Some(false));
+        assert_eq!(date(2024, 10, 20).is_statutory_holiday(), Some(false));
+        assert_eq!(date(2024, 10, 12).is_statutory_holiday(), Some(false));
+        assert_eq!(date(2024, 10, 8).is_statutory_holiday(), Some(false));
+        let date = HolidayDate::from_ymd(2023, 10, 1).unwrap();
+        assert_eq!(date.is_statutory_holiday(), None);
+    }
+
+    #[test]
+    fn test_is_triple_pay_day() {
+        for day in 1..=3 {
+            assert_eq!(date(2024, 10, day).is_triple_pay_day(), Some(true));
+        }
+        for day in 4..=7 {
+            assert_eq!(date(2024, 10, day).is_triple_pay_day(), Some(false));
+        }
+        assert_eq!(date(2024, 2, 10).is_triple_pay_day(), Some(true));
+        assert_eq!(date(2024, 2, 12).is_triple_pay_day(), Some(true));
+        assert_eq!(date(2024, 2, 13).is_triple_pay_day(), Some(false));
+        assert_eq!(date(2024, 9, 16).is_triple_pay_day(), Some(false));
+        assert_eq!(date(2024, 9, 17).is_triple_pay_day(), Some(true));
+        assert_eq!(date(2024, 10, 12).is_triple_pay_day(), Some(false));
+        let date = HolidayDate::from_ymd(2023, 10, 1).unwrap();
+        assert_eq!(date.is_triple_pay_day(), None);
+    }
+
+    #[test]
+    fn test_pay_multiplier() {
+        assert_eq!(date(2024, 10, 1).pay_multiplier(), Some(3.0));
+        assert_eq!(date(2024, 2, 11).pay_multiplier(), Some(3.0));
+        assert_eq!(date(2024, 10, 4).pay_multiplier(), Some(2.0));
+        assert_eq!(date(2024, 10, 5).pay_multiplier(), Some(2.0));
+        assert_eq!(date(2024, 10, 19).pay_multiplier(), Some(2.0));
+        assert_eq!(date(2024, 10, 8).pay_multiplier(), Some(1.0));
+        assert_eq!(date(2024, 10, 12).pay_multiplier(), Some(1.0));
+        let date = HolidayDate::from_ymd(2023, 10, 1).unwrap();
+        assert_eq!(date.pay_multiplier(), None);
+    }
+
+    #[test]
+    fn test_is_weekday_holiday() {
+        assert_eq!(date(2024, 1, 1).is_weekday_holiday(), Some(true));
+        assert_eq!(date(2024, 2, 12).is_weekday_holiday(), Some(true));
+        assert_eq!(date(2024, 4, 4).is_weekday_holiday(), Some(true));
+        assert_eq!(date(2024, 5, 1).is_weekday_holiday(), Some(true));
+        assert_eq!(date(2024, 6, 10).is_weekday_holiday(), Some(true));
+        assert_eq!(date(2024, 9, 16).is_weekday_holiday(), Some(true));
+        assert_eq!(date(2024, 10, 7).is_weekday_holiday(), Some(true));
+        assert_eq!(date(2024, 10, 5).is_weekday_holiday(), Some(false));
+        assert_eq!(date(2024, 10, 8).is_weekday_holiday(), Some(false));
+        let date = HolidayDate::from_ymd(2023, 10, 2).unwrap();
+        assert_eq!(date.is_weekday_holiday(), None);
+    }
+
+    #[test]
+    fn test_day_of_week_sweep() {
+        let mut date = HolidayDate::from_ymd(1, 1, 1).unwrap();
+        let mut expected = day_of_week(1, 1, 1);
+        while date.year <= 2100 {
+            assert_eq!(
+                day_of_week(date.year, date.month, date.day),
+                expected,
+                "{date}"
+            );
+            expected = (expected + 1) % 7;
+            date = date.succ().unwrap();
+        }
+        let mut date = HolidayDate::from_ymd(2024, 1, 1).unwrap();
+        while date.year == 2024 {
+            assert!(date.holiday_kind().is_some(), "{date}");
+            date = date.succ().unwrap();
+        }
     }
 
     #[test]
@@ -207,4 +1162,230 @@ mod tests {
             HolidayKind::G1001Workday
         ));
     }
+
+    #[test]
+    fn test_day_of_year() {
+        assert_eq!(date(2024, 1, 1).day_of_year(), Some(1));
+        assert_eq!(date(2024, 2, 29).day_of_year(), Some(60));
+        assert_eq!(date(2024, 10, 1).day_of_year(), Some(275));
+        assert_eq!(date(2024, 12, 31).day_of_year(), Some(366));
+        let invalid = HolidayDate {
+            year: 2023,
+            month: 2,
+            day: 29,
+        };
+        assert_eq!(invalid.day_of_year(), None);
+    }
+
+    #[test]
+    fn test_ordinal() {
+        let date = HolidayDate::from_ymd(2024, 12, 31).unwrap();
+        assert_eq!(date.ordinal(), 366);
+        assert_eq!(HolidayDate::from_yo(2024, 366), Some(date));
+        assert_eq!(
+            HolidayDate::from_yo(2024, 60),
+            HolidayDate::from_ymd(2024, 2, 29)
+        );
+        assert_eq!(
+            HolidayDate::from_yo(2023, 60),
+            HolidayDate::from_ymd(2023, 3, 1)
+        );
+        assert_eq!(HolidayDate::from_yo(2023, 366), None);
+        assert_eq!(HolidayDate::from_yo(2024, 0), None);
+    }
+
+    #[test]
+    fn test_iso_week() {
+        assert_eq!(date(2024, 10, 1).iso_week(), (2024, 40));
+        assert_eq!(date(2024, 1, 1).iso_week(), (2024, 1));
+        assert_eq!(date(2024, 12, 29).iso_week(), (2024, 52));
+        assert_eq!(date(2024, 12, 30).iso_week(), (2025, 1));
+        assert_eq!(date(2021, 1, 3).iso_week(), (2020, 53));
+        assert_eq!(date(2020, 12, 31).iso_week(), (2020, 53));
+        assert_eq!(date(2026, 1, 1).iso_week(), (2026, 1));
+        assert_eq!(date(2027, 1, 1).iso_week(), (2026, 53));
+        assert_eq!(date(1, 1, 1).iso_week(), (1, 1));
+    }
+
+    #[test]
+    fn test_checked_add_days() {
+        assert_eq!(
+            date(2024, 9, 30).checked_add_days(1),
+            Some(date(2024, 10, 1))
+        );
+        assert_eq!(
+            date(2024, 3, 1).checked_add_days(-1),
+            Some(date(2024, 2, 29))
+        );
+        assert_eq!(
+            date(2024, 12, 31).checked_add_days(366),
+            Some(date(2026, 1, 1))
+        );
+        assert_eq!(date(2024, 1, 1).checked_add_days(0), Some(date(2024, 1, 1)));
+        assert_eq!(date(1, 1, 1).checked_add_days(-1), None);
+        assert_eq!(date(65535, 12, 31).checked_add_days(1), None);
+        assert_eq!(date(2024, 1, 1).checked_add_days(i32::MAX), None);
+    }
+
+    #[test]
+    fn test_kind_offset() {
+        let date = HolidayDate::from_ymd(2024, 9, 30).unwrap();
+        assert_eq!(date.kind_offset(1), Some(HolidayKind::G1001Holiday));
+        assert_eq!(date.kind_offset(0), Some(HolidayKind::RegularWorkday));
+        assert_eq!(date.kind_offset(-1), Some(HolidayKind::G1001Workday));
+        assert_eq!(date.kind_offset(93), None);
+        assert_eq!(date.kind_offset(-274), None);
+    }
+
+    #[test]
+    fn test_day_number() {
+        assert_eq!(HolidayDate::from_ymd(1, 1, 1).unwrap().day_number(), 0);
+        assert_eq!(
+            HolidayDate::from_ymd(1970, 1, 1).unwrap().day_number(),
+            719162
+        );
+        let mut date = HolidayDate::from_ymd(1, 1, 1).unwrap();
+        for n in 0..800_000 {
+            assert_eq!(date.day_number(), n);
+            assert_eq!(HolidayDate::from_day_number(n), Some(date));
+            date = date.succ().unwrap();
+        }
+        assert_eq!(HolidayDate::from_day_number(-1), None);
+        assert_eq!(
+            HolidayDate::from_day_number(
+                HolidayDate::from_ymd(u16::MAX, 12, 31)
+                    .unwrap()
+                    .day_number()
+                    + 1
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_u32_value() {
+        let dec31 = HolidayDate::from_ymd(2024, 12, 31).unwrap();
+        let jan1 = HolidayDate::from_ymd(2025, 1, 1).unwrap();
+        let jan6 = HolidayDate::from_ymd(2025, 1, 6).unwrap();
+        assert!(dec31.u32_value() < jan1.u32_value());
+        assert!(jan1.u32_value() < jan6.u32_value());
+        let mut prev = 0;
+        let mut date = HolidayDate::from_ymd(2023, 1, 1).unwrap();
+        while date.year < 2026 {
+            assert!(prev < date.u32_value(), "{date}");
+            prev = date.u32_value();
+            date = date.succ().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_eq_tuple() {
+        let date = HolidayDate::from_ymd(2024, 10, 1).unwrap();
+        assert_eq!(date, (2024, 10, 1));
+        assert_ne!(date, (2024, 1, 10));
+    }
+
+    #[test]
+    fn test_is_recorded() {
+        assert!(is_recorded(date(2024, 10, 1)));
+        assert!(is_recorded(date(2024, 10, 12)));
+        assert!(!is_recorded(date(2024, 10, 5)));
+        assert!(!is_recorded(date(2024, 10, 8)));
+        assert!(!is_recorded(HolidayDate::from_ymd(2023, 10, 1).unwrap()));
+    }
+
+    #[test]
+    fn test_nearest_adjustment() {
+        assert_eq!(
+            date(2024, 10, 1).nearest_adjustment(),
+            Some((date(2024, 10, 1), HolidayKind::G1001Holiday))
+        );
+        assert_eq!(
+            date(2024, 10, 9).nearest_adjustment(),
+            Some((date(2024, 10, 7), HolidayKind::G1001Holiday))
+        );
+        // Equally close to 2024-10-07 and 2024-10-12.
+        assert_eq!(
+            date(2024, 10, 10).nearest_adjustment(),
+            Some((date(2024, 10, 12), HolidayKind::G1001Workday))
+        );
+        assert_eq!(
+            date(2024, 12, 31).nearest_adjustment(),
+            Some((date(2024, 10, 12), HolidayKind::G1001Workday))
+        );
+        assert_eq!(
+            date(2024, 1, 2).nearest_adjustment(),
+            Some((date(2024, 1, 1), HolidayKind::G0101Holiday))
+        );
+        assert_eq!(
+            HolidayDate::from_ymd(2023, 12, 31)
+                .unwrap()
+                .nearest_adjustment(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_swap_pair() {
+        assert_eq!(
+            swap_pair(date(2024, 9, 29)),
+            Some((date(2024, 9, 29), date(2024, 10, 4)))
+        );
+        assert_eq!(
+            swap_pair(date(2024, 10, 4)),
+            Some((date(2024, 9, 29), date(2024, 10, 4)))
+        );
+        assert_eq!(
+            swap_pair(date(2024, 10, 12)),
+            Some((date(2024, 10, 12), date(2024, 10, 7)))
+        );
+        assert_eq!(swap_pair(date(2024, 10, 1)), None);
+        assert_eq!(swap_pair(date(2024, 10, 5)), None);
+        assert_eq!(swap_pair(date(2024, 10, 8)), None);
+    }
+
+    #[test]
+    fn test_holiday_source() {
+        let source = |y, m, d| HolidayDate::from_ymd(y, m, d).unwrap().holiday_source();
+        assert_eq!(
+            source(2024, 10, 1),
+            Some("https://www.gov.cn/zhengce/zhengceku/202310/content_6911528.htm")
+        );
+        assert_eq!(
+            source(2024, 2, 4),
+            Some("https://www.gov.cn/zhengce/zhengceku/202310/content_6911528.htm")
+        );
+        assert_eq!(source(2024, 10, 5), None);
+        assert_eq!(source(2024, 10, 8), None);
+    }
+
+    #[test]
+    fn test_holiday_kind_approx() {
+        let kind = |y, m, d| {
+            HolidayDate::from_ymd(y, m, d)
+                .unwrap()
+                .holiday_kind_approx()
+        };
+        assert!(matches!(kind(2024, 10, 1), HolidayKind::G1001Holiday));
+        assert!(matches!(kind(2030, 1, 1), HolidayKind::RegularWorkday));
+        assert!(matches!(kind(2030, 1, 5), HolidayKind::RegularHoliday));
+    }
+
+    #[test]
+    fn test_holiday_kind_invalid() {
+        assert!(HolidayDate {
+            year: 2024,
+            month: 0,
+            day: 1
+        }
+        .holiday_kind()
+        .is_none());
+        assert!(HolidayDate {
+            year: 2024,
+            month: 2,
+            day: 30
+        }
+        .holiday_kind()
+        .is_none());
+    }
 }
@@ -0,0 +1,444 @@
+use crate::{Festival, HolidayDate, HolidayLike};
+
+/// Returns the first and last day of the run of days off containing January 1 of the year.
+///
+/// The run may start in December of the previous year, when that year is also recorded.
+///
+/// # Errors
+///
+/// Returns `None` when January 1 is a working day, or the year is less than
+/// [`MIN_YEAR`](crate::MIN_YEAR) or greater than [`MAX_YEAR`](crate::MAX_YEAR).
+pub fn new_year_span(year: u16) -> Option<(HolidayDate, HolidayDate)> {
+    HolidayDate::from_ymd(year, 1, 1)?.containing_span()
+}
+
+impl HolidayDate {
+    /// Returns the first and last day of the next holiday period starting after this date.
+    ///
+    /// A holiday period is a contiguous run of days off containing at least one festival holiday,
+    /// including any Saturdays and Sundays bridged into it. Runs consisting only of regular
+    /// weekends are not periods and are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when no such period is recorded before [`MAX_YEAR`](crate::MAX_YEAR) ends.
+    pub fn next_holiday_period(&self) -> Option<(HolidayDate, HolidayDate)> {
+        let mut date = *self;
+        loop {
+            date = date.succ()?;
+            if date.holiday_kind()?.is_festival_holiday() {
+                let (start, end) = date.rest_span();
+                if start > *self {
+                    return Some((start, end));
+                }
+                date = end;
+            }
+        }
+    }
+
+    /// Returns the first and last day of the previous holiday period ending before this date.
+    ///
+    /// See [`next_holiday_period`](Self::next_holiday_period) for what counts as a period.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when no such period is recorded after [`MIN_YEAR`](crate::MIN_YEAR) begins.
+    pub fn prev_holiday_period(&self) -> Option<(HolidayDate, HolidayDate)> {
+        let mut date = *self;
+        loop {
+            date = date.pred()?;
+            if date.holiday_kind()?.is_festival_holiday() {
+                let (start, end) = date.rest_span();
+                if end < *self {
+                    return Some((start, end));
+                }
+                date = start;
+            }
+        }
+    }
+
+    /// Returns the first day of the next break of the festival starting after this date, e.g. for
+    /// a countdown to National Day.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when no such break is recorded before [`MAX_YEAR`](crate::MAX_YEAR) ends.
+    pub fn next_occurrence(&self, festival: Festival) -> Option<HolidayDate> {
+        let mut date = *self;
+        loop {
+            let (start, end) = date.next_holiday_period()?;
+            if start.break_festival_of() == Some(festival) {
+                return Some(start);
+            }
+            date = end;
+        }
+    }
+
+    /// Returns the first day of the previous break of the festival ending before this date.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when no such break is recorded after [`MIN_YEAR`](crate::MIN_YEAR) begins.
+    pub fn previous_occurrence(&self, festival: Festival) -> Option<HolidayDate> {
+        let mut date = *self;
+        loop {
+            let (start, _) = date.prev_holiday_period()?;
+            if start.break_festival_of() == Some(festival) {
+                return Some(start);
+            }
+            date = start;
+        }
+    }
+
+    /// Returns the first and last day of the contiguous run of days off containing this date,
+    /// including runs consisting only of regular weekends.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when this date is a working day, or the year is less than
+    /// [`MIN_YEAR`](crate::MIN_YEAR) or greater than [`MAX_YEAR`](crate::MAX_YEAR).
+    pub fn containing_span(&self) -> Option<(HolidayDate, HolidayDate)> {
+        self.is_holiday()?.then(|| self.rest_span())
+    }
+
+    /// Returns the 1-based position of this date in its run of days off and the length of the run,
+    /// e.g. `(3, 7)` for the third day of a seven-day break.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when [`containing_span`](Self::containing_span) does.
+    pub fn day_index_in_span(&self) -> Option<(u32, u32)> {
+        let (start, end) = self.containing_span()?;
+        Some((
+            (self.day_number() - start.day_number() + 1) as u32,
+            (end.day_number() - start.day_number() + 1) as u32,
+        ))
+    }
+
+    /// Returns the English name of the festival whose holidays anchor the run of days off
+    /// containing this date, e.g. `National Day` for a Saturday bridged into the break.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when [`containing_span`](Self::containing_span) does, or the run has no
+    /// festival holiday, such as a regular weekend.
+    #[cfg(feature = "names")]
+    pub fn break_festival(&self) -> Option<&'static str> {
+        Some(self.break_festival_of()?.name())
+    }
+
+    /// Returns the festival anchoring the run of days off containing this date.
+    pub(crate) fn break_festival_of(&self) -> Option<Festival> {
+        let (start, end) = self.containing_span()?;
+        let mut date = start;
+        loop {
+            let kind = date.holiday_kind()?;
+            if kind.is_festival_holiday() {
+                return kind.festival();
+            }
+            if date == end {
+                return None;
+            }
+            date = date.succ()?;
+        }
+    }
+
+    /// Returns whether both dates are in the same contiguous run of days off, or the same
+    /// contiguous run of working days.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when the year of either date is less than [`MIN_YEAR`](crate::MIN_YEAR) or
+    /// greater than [`MAX_YEAR`](crate::MAX_YEAR).
+    pub fn same_span(&self, other: HolidayDate) -> Option<bool> {
+        let status = self.is_holiday()?;
+        if other.is_holiday()? != status {
+            return Some(false);
+        }
+        let (start, end) = self.status_span(status);
+        Some(start <= other && other <= end)
+    }
+
+    /// Returns whether this date is a working day with a day off immediately before and after,
+    /// so that taking it off joins both into one longer break.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when this date, or either neighbour of a working day, is less than
+    /// [`MIN_DATE`](crate::MIN_DATE) or greater than [`MAX_DATE`](crate::MAX_DATE).
+    pub fn is_bridge_day(&self) -> Option<bool> {
+        is_bridge_day_with(*self, |d| d.is_holiday())
+    }
+
+    /// Returns whether this date is a working day right after one or more days off, e.g. the day
+    /// back at work after a break or a regular weekend.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when this date, or the day before a working day, is less than
+    /// [`MIN_DATE`](crate::MIN_DATE) or greater than [`MAX_DATE`](crate::MAX_DATE).
+    pub fn is_first_workday_after_break(&self) -> Option<bool> {
+        Some(!self.is_holiday()? && self.pred()?.is_holiday()?)
+    }
+
+    /// Returns whether this date is a working day right before one or more days off, e.g. the last
+    /// day at work before a break or a regular weekend.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when this date, or the day after a working day, is less than
+    /// [`MIN_DATE`](crate::MIN_DATE) or greater than [`MAX_DATE`](crate::MAX_DATE).
+    pub fn is_last_workday_before_break(&self) -> Option<bool> {
+        Some(!self.is_holiday()? && self.succ()?.is_holiday()?)
+    }
+
+    /// Returns the contiguous run of days off around a day off.
+    fn rest_span(&self) -> (HolidayDate, HolidayDate) {
+        self.status_span(true)
+    }
+
+    /// Returns the contiguous run of days with the status around a day with the status.
+    fn status_span(&self, status: bool) -> (HolidayDate, HolidayDate) {
+        run_around(*self, |d| d.is_holiday() == Some(status))
+    }
+}
+
+/// Returns whether the date is a working day between two days off under the classifier.
+fn is_bridge_day_with(
+    date: HolidayDate,
+    is_holiday: impl Fn(HolidayDate) -> Option<bool>,
+) -> Option<bool> {
+    Some(!is_holiday(date)? && is_holiday(date.pred()?)? && is_holiday(date.succ()?)?)
+}
+
+/// Returns the contiguous run of days matching the predicate around a matching date.
+pub(crate) fn run_around(
+    date: HolidayDate,
+    matches: impl Fn(HolidayDate) -> bool,
+) -> (HolidayDate, HolidayDate) {
+    let mut start = date;
+    while let Some(date) = start.pred().filter(|d| matches(*d)) {
+        start = date;
+    }
+    let mut end = date;
+    while let Some(date) = end.succ().filter(|d| matches(*d)) {
+        end = date;
+    }
+    (start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: u16, month: u8, day: u8) -> HolidayDate {
+        HolidayDate::from_ymd(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_next_holiday_period() {
+        assert_eq!(
+            date(2024, 7, 1).next_holiday_period(),
+            Some((date(2024, 9, 15), date(2024, 9, 17)))
+        );
+        assert_eq!(
+            date(2024, 9, 18).next_holiday_period(),
+            Some((date(2024, 10, 1), date(2024, 10, 7)))
+        );
+        assert_eq!(date(2024, 10, 3).next_holiday_period(), None);
+    }
+
+    #[test]
+    fn test_next_occurrence() {
+        assert_eq!(
+            date(2024, 7, 1).next_occurrence(Festival::NationalDay),
+            Some(date(2024, 10, 1))
+        );
+        assert_eq!(
+            date(2024, 1, 1).next_occurrence(Festival::MidAutumn),
+            Some(date(2024, 9, 15))
+        );
+        assert_eq!(date(2024, 9, 16).next_occurrence(Festival::MidAutumn), None);
+        assert_eq!(
+            date(2024, 3, 1).next_occurrence(Festival::SpringFestival),
+            None
+        );
+    }
+
+    #[test]
+    fn test_previous_occurrence() {
+        assert_eq!(
+            date(2024, 10, 8).previous_occurrence(Festival::NationalDay),
+            Some(date(2024, 10, 1))
+        );
+        assert_eq!(
+            date(2024, 12, 31).previous_occurrence(Festival::SpringFestival),
+            Some(date(2024, 2, 10))
+        );
+        assert_eq!(
+            date(2024, 10, 5).previous_occurrence(Festival::NationalDay),
+            None
+        );
+        assert_eq!(
+            date(2024, 12, 31).previous_occurrence(Festival::NewYear),
+            Some(date(2024, 1, 1))
+        );
+        assert_eq!(
+            date(2024, 1, 1).previous_occurrence(Festival::NewYear),
+            None
+        );
+    }
+
+    #[test]
+    fn test_new_year_span() {
+        assert_eq!(
+            new_year_span(2024),
+            Some((date(2024, 1, 1), date(2024, 1, 1)))
+        );
+        assert_eq!(new_year_span(2023), None);
+    }
+
+    #[test]
+    fn test_containing_span() {
+        assert_eq!(
+            date(2024, 10, 5).containing_span(),
+            Some((date(2024, 10, 1), date(2024, 10, 7)))
+        );
+        assert_eq!(
+            date(2024, 10, 19).containing_span(),
+            Some((date(2024, 10, 19), date(2024, 10, 20)))
+        );
+        assert_eq!(date(2024, 10, 12).containing_span(), None);
+        assert_eq!(date(2023, 10, 1).containing_span(), None);
+    }
+
+    #[test]
+    fn test_day_index_in_span() {
+        assert_eq!(date(2024, 10, 3).day_index_in_span(), Some((3, 7)));
+        assert_eq!(date(2024, 2, 10).day_index_in_span(), Some((1, 8)));
+        assert_eq!(date(2024, 10, 20).day_index_in_span(), Some((2, 2)));
+        assert_eq!(date(2024, 10, 8).day_index_in_span(), None);
+    }
+
+    #[cfg(feature = "names")]
+    #[test]
+    fn test_break_festival() {
+        assert_eq!(date(2024, 10, 5).break_festival(), Some("National Day"));
+        assert_eq!(date(2024, 2, 10).break_festival(), Some("Spring Festival"));
+        assert_eq!(
+            date(2024, 6, 8).break_festival(),
+            Some("Dragon Boat Festival")
+        );
+        assert_eq!(date(2024, 10, 19).break_festival(), None);
+        assert_eq!(date(2024, 10, 12).break_festival(), None);
+    }
+
+    #[test]
+    fn test_same_span() {
+        assert_eq!(date(2024, 10, 1).same_span(date(2024, 10, 7)), Some(true));
+        assert_eq!(date(2024, 10, 7).same_span(date(2024, 10, 1)), Some(true));
+        assert_eq!(date(2024, 10, 7).same_span(date(2024, 10, 8)), Some(false));
+        assert_eq!(date(2024, 10, 8).same_span(date(2024, 10, 12)), Some(true));
+        assert_eq!(date(2024, 10, 8).same_span(date(2024, 10, 14)), Some(false));
+        assert_eq!(
+            date(2024, 10, 19).same_span(date(2024, 10, 26)),
+            Some(false)
+        );
+        assert_eq!(date(2024, 10, 1).same_span(date(2023, 10, 1)), None);
+    }
+
+    #[test]
+    fn test_is_bridge_day() {
+        // The 2024 arrangement leaves no working day isolated between days off.
+        let mut d = date(2024, 1, 1);
+        while d <= date(2024, 12, 31) {
+            assert_eq!(d.is_bridge_day(), Some(false), "{d}");
+            d = d.succ().unwrap();
+        }
+        assert_eq!(date(2024, 10, 8).is_bridge_day(), Some(false));
+        assert_eq!(date(2024, 10, 12).is_bridge_day(), Some(false));
+        assert_eq!(date(2024, 10, 16).is_bridge_day(), Some(false));
+        assert_eq!(date(2023, 10, 9).is_bridge_day(), None);
+
+        // A synthetic arrangement taking 2024-10-08 and 2024-10-10 off, isolating 2024-10-09.
+        let is_holiday = |d: HolidayDate| {
+            Some(d == date(2024, 10, 8) || d == date(2024, 10, 10) || d.is_holiday()?)
+        };
+        assert_eq!(
+            is_bridge_day_with(date(2024, 10, 9), is_holiday),
+            Some(true)
+        );
+        assert_eq!(
+            is_bridge_day_with(date(2024, 10, 8), is_holiday),
+            Some(false)
+        );
+        assert_eq!(
+            is_bridge_day_with(date(2024, 10, 11), is_holiday),
+            Some(false)
+        );
+        assert_eq!(is_bridge_day_with(date(2023, 10, 9), is_holiday), None);
+    }
+
+    #[test]
+    fn test_is_first_workday_after_break() {
+        assert_eq!(date(2024, 10, 8).is_first_workday_after_break(), Some(true));
+        assert_eq!(
+            date(2024, 10, 21).is_first_workday_after_break(),
+            Some(true)
+        );
+        assert_eq!(
+            date(2024, 10, 12).is_first_workday_after_break(),
+            Some(false)
+        );
+        assert_eq!(
+            date(2024, 10, 9).is_first_workday_after_break(),
+            Some(false)
+        );
+        assert_eq!(
+            date(2024, 10, 7).is_first_workday_after_break(),
+            Some(false)
+        );
+        assert_eq!(date(2024, 9, 29).is_first_workday_after_break(), Some(true));
+        assert_eq!(date(2023, 10, 9).is_first_workday_after_break(), None);
+    }
+
+    #[test]
+    fn test_is_last_workday_before_break() {
+        assert_eq!(date(2024, 9, 30).is_last_workday_before_break(), Some(true));
+        assert_eq!(
+            date(2024, 10, 18).is_last_workday_before_break(),
+            Some(true)
+        );
+        assert_eq!(
+            date(2024, 9, 29).is_last_workday_before_break(),
+            Some(false)
+        );
+        assert_eq!(
+            date(2024, 10, 12).is_last_workday_before_break(),
+            Some(true)
+        );
+        assert_eq!(
+            date(2024, 10, 1).is_last_workday_before_break(),
+            Some(false)
+        );
+        assert_eq!(date(2024, 12, 31).is_last_workday_before_break(), None);
+        assert_eq!(date(2023, 9, 28).is_last_workday_before_break(), None);
+    }
+
+    #[test]
+    fn test_prev_holiday_period() {
+        assert_eq!(
+            date(2024, 10, 8).prev_holiday_period(),
+            Some((date(2024, 10, 1), date(2024, 10, 7)))
+        );
+        assert_eq!(
+            date(2024, 10, 3).prev_holiday_period(),
+            Some((date(2024, 9, 15), date(2024, 9, 17)))
+        );
+        assert_eq!(
+            date(2024, 6, 9).prev_holiday_period(),
+            Some((date(2024, 5, 1), date(2024, 5, 5)))
+        );
+        assert_eq!(date(2024, 1, 1).prev_holiday_period(), None);
+    }
+}
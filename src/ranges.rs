@@ -0,0 +1,1040 @@
+#[cfg(feature = "alloc")]
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::{
+    iter,
+    ops::{Bound, RangeBounds},
+};
+
+use crate::{holidays, HolidayDate, HolidayLike, MAX_DATE, MIN_DATE};
+#[cfg(feature = "alloc")]
+use crate::{HolidayKind, MAX_YEAR, MIN_YEAR};
+
+/// Returns consecutive days from `start` through `end` inclusive.
+fn days(start: HolidayDate, end: HolidayDate) -> impl Iterator<Item = HolidayDate> {
+    iter::successors(Some(start), HolidayDate::succ).take_while(move |date| *date <= end)
+}
+
+/// Returns consecutive days from `start` through `end` inclusive, or `None` when `start` is after
+/// `end` or either is outside [`MIN_DATE`]..=[`MAX_DATE`].
+fn supported_days(
+    start: HolidayDate,
+    end: HolidayDate,
+) -> Option<impl Iterator<Item = HolidayDate>> {
+    if MIN_DATE <= start && start <= end && end <= MAX_DATE {
+        Some(days(start, end))
+    } else {
+        None
+    }
+}
+
+/// Returns consecutive days of the year, or `None` when the year is less than
+/// [`MIN_YEAR`](crate::MIN_YEAR) or greater than [`MAX_YEAR`](crate::MAX_YEAR).
+fn year_days(year: u16) -> Option<impl Iterator<Item = HolidayDate>> {
+    supported_days(
+        HolidayDate {
+            year,
+            month: 1,
+            day: 1,
+        },
+        HolidayDate {
+            year,
+            month: 12,
+            day: 31,
+        },
+    )
+}
+
+/// An inclusive range of dates.
+///
+/// ```
+/// use chinese_mainland_holidays::{HolidayDate, HolidayDateRange};
+///
+/// let range = HolidayDateRange::new(
+///     HolidayDate::from_ymd(2024, 9, 30).unwrap(),
+///     HolidayDate::from_ymd(2024, 10, 13).unwrap(),
+/// )
+/// .unwrap();
+/// assert_eq!(range.len(), 14);
+/// assert_eq!(range.workdays().count(), 6);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HolidayDateRange {
+    start: HolidayDate,
+    end: HolidayDate,
+}
+
+impl HolidayDateRange {
+    /// Constructs from the first and last day.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when `start` is after `end`.
+    pub fn new(start: HolidayDate, end: HolidayDate) -> Option<Self> {
+        (start <= end).then_some(Self { start, end })
+    }
+
+    /// Returns the first day.
+    pub fn start(&self) -> HolidayDate {
+        self.start
+    }
+
+    /// Returns the last day.
+    pub fn end(&self) -> HolidayDate {
+        self.end
+    }
+
+    /// Returns the number of calendar days.
+    pub fn len(&self) -> u32 {
+        (crate::days_between(self.start, self.end) + 1) as u32
+    }
+
+    /// Returns whether there are no days, which is never the case since the range is inclusive.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns whether the date is in the range.
+    pub fn contains(&self, date: &HolidayDate) -> bool {
+        RangeBounds::contains(self, date)
+    }
+
+    /// Returns every day in the range.
+    pub fn iter(&self) -> impl Iterator<Item = HolidayDate> {
+        days(self.start, self.end)
+    }
+
+    /// Returns the working days in the range.
+    ///
+    /// Days before [`MIN_DATE`] or after [`MAX_DATE`] are neither working days nor days off.
+    pub fn workdays(&self) -> impl Iterator<Item = HolidayDate> {
+        self.iter().filter(|date| date.is_holiday() == Some(false))
+    }
+
+    /// Returns the days off in the range.
+    ///
+    /// Days before [`MIN_DATE`] or after [`MAX_DATE`] are neither working days nor days off.
+    pub fn holidays(&self) -> impl Iterator<Item = HolidayDate> {
+        self.iter().filter(|date| date.is_holiday() == Some(true))
+    }
+}
+
+impl RangeBounds<HolidayDate> for HolidayDateRange {
+    fn start_bound(&self) -> Bound<&HolidayDate> {
+        Bound::Included(&self.start)
+    }
+
+    fn end_bound(&self) -> Bound<&HolidayDate> {
+        Bound::Included(&self.end)
+    }
+}
+
+/// Counts of the days in a range, returned by [`range_summary`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RangeSummary {
+    /// Number of calendar days.
+    pub total_days: u32,
+    /// Number of working days, including adjusted working days.
+    pub workdays: u32,
+    /// Number of days off, including festival holidays.
+    pub days_off: u32,
+    /// Number of Saturdays and Sundays adjusted to working days.
+    pub makeup_workdays: u32,
+    /// Number of weekdays that are festival holidays.
+    pub statutory_holidays: u32,
+}
+
+/// Effect of the arrangement of a year compared with a plain Monday to Friday calendar, returned
+/// by [`statutory_impact`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StatutoryImpact {
+    /// Number of weekdays that are festival holidays.
+    pub extra_days_off: u32,
+    /// Number of Saturdays and Sundays adjusted to working days.
+    pub weekends_worked: u32,
+}
+
+/// Counts the days from `start` through `end` inclusive in one pass.
+///
+/// # Errors
+///
+/// Returns `None` when `start` is after `end`, or either is less than [`MIN_DATE`] or greater than
+/// [`MAX_DATE`].
+pub fn range_summary(start: HolidayDate, end: HolidayDate) -> Option<RangeSummary> {
+    supported_days(start, end)?.try_fold(RangeSummary::default(), |mut summary, date| {
+        let kind = date.holiday_kind()?;
+        let day_off = kind.is_day_off();
+        summary.total_days += 1;
+        summary.workdays += !day_off as u32;
+        summary.days_off += day_off as u32;
+        summary.makeup_workdays += date.is_worked_weekend()? as u32;
+        summary.statutory_holidays += kind.is_festival_holiday() as u32;
+        Some(summary)
+    })
+}
+
+/// Returns the number of Saturdays and Sundays adjusted to working days from `start` through
+/// `end` inclusive.
+///
+/// # Errors
+///
+/// Returns `None` when `start` is after `end` or either is outside [`MIN_DATE`]..=[`MAX_DATE`].
+pub fn makeup_workdays_between(start: HolidayDate, end: HolidayDate) -> Option<u32> {
+    Some(
+        supported_days(start, end)?
+            .filter(|date| date.is_worked_weekend() == Some(true))
+            .count() as u32,
+    )
+}
+
+/// Returns the working hours from `start` through `end` inclusive, counting each working day,
+/// including adjusted working days, as `hours_per_day`.
+///
+/// Every working day is a full day. Half days under
+/// [`WeekendPolicy::HalfSaturday`](crate::WeekendPolicy::HalfSaturday) are not considered.
+///
+/// # Errors
+///
+/// Returns `None` when `start` is after `end` or either is outside [`MIN_DATE`]..=[`MAX_DATE`].
+pub fn working_hours_between(
+    start: HolidayDate,
+    end: HolidayDate,
+    hours_per_day: f32,
+) -> Option<f32> {
+    let workdays = supported_days(start, end)?
+        .filter(|date| date.is_holiday() == Some(false))
+        .count();
+    Some(workdays as f32 * hours_per_day)
+}
+
+/// Returns whether there is any day off from `start` through `end` inclusive, stopping at the
+/// first one found.
+///
+/// # Errors
+///
+/// Returns `None` when `start` is after `end` or either is outside [`MIN_DATE`]..=[`MAX_DATE`].
+pub fn contains_holiday(start: HolidayDate, end: HolidayDate) -> Option<bool> {
+    Some(supported_days(start, end)?.any(|date| date.is_holiday() == Some(true)))
+}
+
+/// Returns the number of days off in the year minus the number of Saturdays and Sundays, i.e.
+/// festival holidays net of adjusted working days.
+///
+/// # Errors
+///
+/// Returns `None` when the year is less than [`MIN_YEAR`](crate::MIN_YEAR) or greater than
+/// [`MAX_YEAR`](crate::MAX_YEAR).
+pub fn net_extra_rest_days(year: u16) -> Option<i32> {
+    year_days(year)?.try_fold(0, |net, date| {
+        Some(net + date.is_holiday()? as i32 - date.regular_kind().is_day_off() as i32)
+    })
+}
+
+/// Returns January 1 of the year with its status, followed by each date in the year where the
+/// status flips, with the new status. The status is `true` for a day off.
+///
+/// # Errors
+///
+/// Returns `None` when the year is less than [`MIN_YEAR`](crate::MIN_YEAR) or greater than
+/// [`MAX_YEAR`](crate::MAX_YEAR).
+pub fn transitions_in_year(year: u16) -> Option<impl Iterator<Item = (HolidayDate, bool)>> {
+    let mut prev = None;
+    Some(year_days(year)?.filter_map(move |date| {
+        let status = date.is_holiday()?;
+        (prev.replace(status) != Some(status)).then_some((date, status))
+    }))
+}
+
+/// Compares the arrangement of the year with a plain Monday to Friday calendar, e.g. 19 extra days
+/// off and 8 weekend days worked in 2024.
+///
+/// See [`net_extra_rest_days`] for the difference of both.
+///
+/// # Errors
+///
+/// Returns `None` when the year is less than [`MIN_YEAR`](crate::MIN_YEAR) or greater than
+/// [`MAX_YEAR`](crate::MAX_YEAR).
+pub fn statutory_impact(year: u16) -> Option<StatutoryImpact> {
+    let summary = range_summary(
+        HolidayDate::from_ymd(year, 1, 1)?,
+        HolidayDate::from_ymd(year, 12, 31)?,
+    )?;
+    Some(StatutoryImpact {
+        extra_days_off: summary.statutory_holidays,
+        weekends_worked: summary.makeup_workdays,
+    })
+}
+
+/// Returns the first day off of the year.
+///
+/// # Errors
+///
+/// Returns `None` when the year is less than [`MIN_YEAR`](crate::MIN_YEAR) or greater than
+/// [`MAX_YEAR`](crate::MAX_YEAR).
+pub fn first_holiday_of_year(year: u16) -> Option<HolidayDate> {
+    year_days(year)?.find(|date| date.is_holiday() == Some(true))
+}
+
+/// Returns the last day off of the year.
+///
+/// # Errors
+///
+/// Returns `None` when the year is less than [`MIN_YEAR`](crate::MIN_YEAR) or greater than
+/// [`MAX_YEAR`](crate::MAX_YEAR).
+pub fn last_holiday_of_year(year: u16) -> Option<HolidayDate> {
+    year_days(year)?
+        .filter(|date| date.is_holiday() == Some(true))
+        .last()
+}
+
+/// Returns every day of the year in order, paired with whether it is a day off.
+///
+/// Unlike querying each day with [`HolidayLike::is_holiday`], this walks the recorded adjustments
+/// alongside the day of week, for rendering a whole year at once. The records are those of the
+/// table set with [`set_active_table`](crate::set_active_table) if any, as with
+/// [`HolidayLike::is_holiday`].
+///
+/// # Errors
+///
+/// Returns `None` when the year is less than [`MIN_YEAR`](crate::MIN_YEAR) or greater than
+/// [`MAX_YEAR`](crate::MAX_YEAR), or the active table has no records of the year.
+pub fn year_calendar(year: u16) -> Option<impl Iterator<Item = (HolidayDate, bool)>> {
+    let mut days = year_days(year)?.peekable();
+    #[cfg(feature = "std")]
+    let active = match crate::active::active_statuses(year) {
+        Some(statuses) => Some(statuses?),
+        None => None,
+    };
+    #[cfg(not(feature = "std"))]
+    let active: Option<iter::Empty<(u32, bool)>> = None;
+    let first = days.peek()?.u32_value();
+    let builtin = active.is_none().then(|| {
+        holidays::HOLIDAYS[holidays::HOLIDAYS.partition_point(|(v, _)| *v < first)..]
+            .iter()
+            .map(|(v, kind)| (*v, kind.is_day_off()))
+    });
+    let mut records = active
+        .into_iter()
+        .flatten()
+        .chain(builtin.into_iter().flatten())
+        .peekable();
+    let mut weekday = days.peek()?.num_days_from_sunday();
+    Some(days.map(move |date| {
+        let regular = matches!(weekday, 0 | 6);
+        weekday = (weekday + 1) % 7;
+        match records.next_if(|(v, _)| *v == date.u32_value()) {
+            Some((_, off)) => (date, off),
+            None => (date, regular),
+        }
+    }))
+}
+
+/// Returns the share of days off in the month, accounting for festival holidays and adjusted
+/// working days.
+///
+/// # Errors
+///
+/// Returns `None` when the month is invalid, or the year is less than
+/// [`MIN_YEAR`](crate::MIN_YEAR) or greater than [`MAX_YEAR`](crate::MAX_YEAR).
+pub fn rest_ratio(year: u16, month: u8) -> Option<f32> {
+    let len = crate::days_in_month(year, month)?;
+    let start = HolidayDate::from_ymd(year, month, 1)?;
+    let end = HolidayDate::from_ymd(year, month, len)?;
+    let days_off = supported_days(start, end)?
+        .filter(|date| date.is_holiday() == Some(true))
+        .count();
+    Some(days_off as f32 / len as f32)
+}
+
+/// Returns the days off in the ISO 8601 week and their holiday kind, in order.
+///
+/// See [`HolidayDate::iso_week`] for the week numbering.
+///
+/// # Errors
+///
+/// Returns `None` when the week is not in the ISO year, or any day of the week is less than
+/// [`MIN_DATE`] or greater than [`MAX_DATE`].
+#[cfg(feature = "alloc")]
+pub fn holidays_in_iso_week(iso_year: u16, week: u8) -> Option<Vec<(HolidayDate, HolidayKind)>> {
+    if week == 0 || week > crate::iso_weeks_in_year(iso_year) {
+        return None;
+    }
+    // January 4 is always in week 1.
+    let jan4 = HolidayDate::from_ymd(iso_year, 1, 4)?;
+    let monday = (crate::day_of_week(iso_year, 1, 4) + 6) % 7;
+    let start =
+        HolidayDate::from_day_number(jan4.day_number() - monday as i32 + (week as i32 - 1) * 7)?;
+    let end = HolidayDate::from_day_number(start.day_number() + 6)?;
+    Some(
+        supported_days(start, end)?
+            .filter_map(|date| {
+                let kind = date.holiday_kind()?;
+                kind.is_day_off().then_some((date, kind))
+            })
+            .collect(),
+    )
+}
+
+/// Returns the number of days of each holiday kind in the year, keyed by the kind name, e.g.
+/// `G1001Holiday`.
+///
+/// Regular Saturdays, Sundays and weekdays are counted as `RegularHoliday` and `RegularWorkday`.
+/// Kinds not occurring in the year are omitted.
+///
+/// # Errors
+///
+/// Returns `None` when the year is less than [`MIN_YEAR`] or greater than [`MAX_YEAR`].
+#[cfg(feature = "alloc")]
+pub fn kind_histogram(year: u16) -> Option<BTreeMap<&'static str, u32>> {
+    year_days(year)?.try_fold(BTreeMap::new(), |mut histogram, date| {
+        *histogram.entry(date.holiday_kind()?.name()).or_insert(0) += 1;
+        Some(histogram)
+    })
+}
+
+/// Returns the days of the year whose holiday kind satisfies the predicate, in order.
+///
+/// # Errors
+///
+/// Returns `None` when the year is less than [`MIN_YEAR`] or greater than [`MAX_YEAR`].
+#[cfg(feature = "alloc")]
+pub fn filter_year<F: Fn(HolidayKind) -> bool>(year: u16, pred: F) -> Option<Vec<HolidayDate>> {
+    Some(
+        year_days(year)?
+            .filter(|date| date.holiday_kind().is_some_and(&pred))
+            .collect(),
+    )
+}
+
+/// Returns the first and last day of each contiguous run of at least `min_len` days off from
+/// `start` through `end` inclusive, in order, e.g. for surfacing long weekends.
+///
+/// Runs are cut off at `start` and `end`. Returns an empty `Vec` when `start` is after `end`, or
+/// either is less than [`MIN_DATE`] or greater than [`MAX_DATE`].
+#[cfg(feature = "alloc")]
+pub fn long_weekends(
+    start: HolidayDate,
+    end: HolidayDate,
+    min_len: u32,
+) -> Vec<(HolidayDate, HolidayDate)> {
+    let mut spans = Vec::new();
+    let mut run: Option<(HolidayDate, HolidayDate, u32)> = None;
+    for date in supported_days(start, end).into_iter().flatten() {
+        if date.is_holiday() == Some(true) {
+            run = Some(match run {
+                Some((first, _, len)) => (first, date, len + 1),
+                None => (date, date, 1),
+            });
+        } else if let Some((first, last, len)) = run.take() {
+            if len >= min_len {
+                spans.push((first, last));
+            }
+        }
+    }
+    if let Some((first, last, len)) = run {
+        if len >= min_len {
+            spans.push((first, last));
+        }
+    }
+    spans
+}
+
+/// Returns the Saturdays and Sundays adjusted to working days in the month, in order, e.g.
+/// 2024-09-14 and 2024-09-29 for September 2024.
+///
+/// Returns an empty `Vec` when the month is invalid, or the year is less than [`MIN_YEAR`] or
+/// greater than [`MAX_YEAR`].
+#[cfg(feature = "alloc")]
+pub fn makeup_workdays_in_month(year: u16, month: u8) -> Vec<HolidayDate> {
+    let Some(len) = crate::days_in_month(year, month) else {
+        return Vec::new();
+    };
+    let start = HolidayDate {
+        year,
+        month,
+        day: 1,
+    };
+    supported_days(start, HolidayDate { day: len, ..start })
+        .into_iter()
+        .flatten()
+        .filter(|date| date.is_worked_weekend() == Some(true))
+        .collect()
+}
+
+/// Returns the recorded festival holidays and adjusted working days of the year, keyed by date
+/// for range queries.
+///
+/// Regular Saturdays, Sundays and weekdays are not included.
+///
+/// # Errors
+///
+/// Returns `None` when the year is less than [`MIN_YEAR`] or greater than [`MAX_YEAR`].
+#[cfg(feature = "alloc")]
+pub fn year_map(year: u16) -> Option<BTreeMap<HolidayDate, HolidayKind>> {
+    if !(MIN_YEAR..=MAX_YEAR).contains(&year) {
+        return None;
+    }
+    Some(
+        holidays::records()
+            .filter(|(date, _)| date.year == year)
+            .collect(),
+    )
+}
+
+/// Filters iterators of dates by their status.
+///
+/// ```
+/// use chinese_mainland_holidays::{HolidayDate, HolidayDateIteratorExt};
+///
+/// let dates = (1..=8).filter_map(|day| HolidayDate::from_ymd(2024, 10, day));
+/// assert_eq!(dates.workday_filter().count(), 1);
+/// ```
+pub trait HolidayDateIteratorExt: Iterator<Item = HolidayDate> + Sized {
+    /// Yields only the days off, skipping dates less than [`MIN_DATE`] or greater than
+    /// [`MAX_DATE`].
+    fn holiday_filter(self) -> impl Iterator<Item = HolidayDate> {
+        self.filter(|date| date.is_holiday() == Some(true))
+    }
+
+    /// Yields only the working days, including adjusted working days, skipping dates less than
+    /// [`MIN_DATE`] or greater than [`MAX_DATE`].
+    fn workday_filter(self) -> impl Iterator<Item = HolidayDate> {
+        self.filter(|date| date.is_holiday() == Some(false))
+    }
+}
+
+impl<I: Iterator<Item = HolidayDate>> HolidayDateIteratorExt for I {}
+
+impl HolidayDate {
+    /// Returns the date `n` working days after this date, stepping one day at a time. Returns this
+    /// date when `n` is zero.
+    ///
+    /// Use [`YearWorkdayIndex`](crate::YearWorkdayIndex) for many queries within a year.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when the result would be after [`MAX_DATE`].
+    pub fn add_workdays(&self, n: u32) -> Option<HolidayDate> {
+        let mut date = *self;
+        for _ in 0..n {
+            loop {
+                date = date.succ()?;
+                if !date.is_holiday()? {
+                    break;
+                }
+            }
+        }
+        Some(date)
+    }
+
+    /// Returns the first working day after this date, including adjusted working days.
+    ///
+    /// The search stops at [`MAX_DATE`] rather than guessing from the day of week beyond it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when no working day is recorded after this date.
+    pub fn next_workday(&self) -> Option<HolidayDate> {
+        self.succ()?.snap_to_workday_forward()
+    }
+
+    /// Returns the last working day before this date, including adjusted working days.
+    ///
+    /// The search stops at [`MIN_DATE`] rather than guessing from the day of week before it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when no working day is recorded before this date.
+    pub fn prev_workday(&self) -> Option<HolidayDate> {
+        self.pred()?.snap_to_workday_backward()
+    }
+
+    /// Returns this date when it is a working day, including an adjusted working day, or else the
+    /// next working day, e.g. for rolling a due date forward.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when this date is less than [`MIN_DATE`], or the result would be after
+    /// [`MAX_DATE`].
+    pub fn snap_to_workday_forward(&self) -> Option<HolidayDate> {
+        let mut date = *self;
+        while date.is_holiday()? {
+            date = date.succ()?;
+        }
+        Some(date)
+    }
+
+    /// Returns this date when it is a working day, including an adjusted working day, or else the
+    /// previous working day.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when this date is greater than [`MAX_DATE`], or the result would be before
+    /// [`MIN_DATE`].
+    pub fn snap_to_workday_backward(&self) -> Option<HolidayDate> {
+        let mut date = *self;
+        while date.is_holiday()? {
+            date = date.pred()?;
+        }
+        Some(date)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl HolidayDate {
+    /// Returns the days off from this date through `within` days later, inclusive.
+    ///
+    /// The window is truncated at [`MAX_DATE`], and days before [`MIN_DATE`] are
+    /// skipped, since they cannot be classified.
+    pub fn upcoming_days_off(&self, within: u32) -> Vec<HolidayDate> {
+        days(*self, MAX_DATE)
+            .take((within as usize).saturating_add(1))
+            .filter(|date| date.is_holiday() == Some(true))
+            .collect()
+    }
+
+    /// Returns the next `n` working days strictly after this date in order, including adjusted
+    /// working days.
+    ///
+    /// Fewer than `n` days are returned when the rest are after [`MAX_DATE`], and days before
+    /// [`MIN_DATE`] are skipped, since they cannot be classified.
+    pub fn next_workdays(&self, n: usize) -> Vec<HolidayDate> {
+        let Some(start) = self.succ() else {
+            return Vec::new();
+        };
+        days(start.max(MIN_DATE), MAX_DATE)
+            .filter(|date| date.is_holiday() == Some(false))
+            .take(n)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: u16, month: u8, day: u8) -> HolidayDate {
+        HolidayDate::from_ymd(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_makeup_workdays_between() {
+        assert_eq!(
+            makeup_workdays_between(date(2024, 1, 1), date(2024, 12, 31)),
+            Some(8)
+        );
+        assert_eq!(
+            makeup_workdays_between(date(2024, 1, 1), date(2024, 6, 30)),
+            Some(5)
+        );
+        assert_eq!(
+            makeup_workdays_between(date(2024, 10, 13), date(2024, 12, 31)),
+            Some(0)
+        );
+        assert_eq!(
+            makeup_workdays_between(date(2024, 6, 30), date(2024, 1, 1)),
+            None
+        );
+        assert_eq!(
+            makeup_workdays_between(date(2023, 12, 1), date(2024, 1, 31)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_add_workdays() {
+        assert_eq!(date(2024, 9, 30).add_workdays(0), Some(date(2024, 9, 30)));
+        assert_eq!(date(2024, 9, 30).add_workdays(1), Some(date(2024, 10, 8)));
+        assert_eq!(date(2024, 10, 3).add_workdays(5), Some(date(2024, 10, 12)));
+        assert_eq!(date(2024, 12, 30).add_workdays(2), None);
+    }
+
+    #[test]
+    fn test_holiday_date_iterator_ext() {
+        let range = HolidayDateRange::new(date(2024, 9, 28), date(2024, 10, 13)).unwrap();
+        assert!(range.iter().holiday_filter().eq(range.holidays()));
+        assert!(range.iter().workday_filter().eq(range.workdays()));
+        assert_eq!(range.iter().holiday_filter().count(), 9);
+        assert_eq!(range.iter().workday_filter().count(), 7);
+        let dates = [date(2023, 10, 1), date(2024, 10, 1), date(2025, 10, 8)];
+        assert!(dates.into_iter().holiday_filter().eq([date(2024, 10, 1)]));
+        assert_eq!(dates.into_iter().workday_filter().count(), 0);
+    }
+
+    #[test]
+    fn test_next_prev_workday() {
+        assert_eq!(date(2024, 9, 30).next_workday(), Some(date(2024, 10, 8)));
+        assert_eq!(date(2024, 10, 8).prev_workday(), Some(date(2024, 9, 30)));
+        assert_eq!(date(2024, 9, 27).next_workday(), Some(date(2024, 9, 29)));
+        assert_eq!(date(2024, 12, 30).next_workday(), Some(date(2024, 12, 31)));
+        assert_eq!(date(2024, 1, 3).prev_workday(), Some(date(2024, 1, 2)));
+        assert_eq!(date(2023, 12, 31).next_workday(), Some(date(2024, 1, 2)));
+    }
+
+    #[test]
+    fn test_stepping_off_supported_years() {
+        // 2025-01-02 is a regular Thursday, but 2025 is not recorded.
+        assert_eq!(date(2024, 12, 31).next_workday(), None);
+        assert_eq!(date(2024, 12, 31).add_workdays(1), None);
+        #[cfg(feature = "alloc")]
+        assert!(date(2024, 12, 31).next_workdays(1).is_empty());
+        assert_eq!(date(2024, 12, 31).next_holiday_period(), None);
+        assert_eq!(date(2024, 12, 31).kind_offset(1), None);
+        assert_eq!(date(2025, 1, 1).snap_to_workday_forward(), None);
+        // 2023-12-29 is a regular Friday, but 2023 is not recorded.
+        assert_eq!(date(2024, 1, 2).prev_workday(), None);
+        assert_eq!(date(2024, 1, 1).snap_to_workday_backward(), None);
+        assert_eq!(date(2024, 1, 1).prev_holiday_period(), None);
+        assert_eq!(date(2024, 1, 1).kind_offset(-1), None);
+    }
+
+    #[test]
+    fn test_snap_to_workday() {
+        assert_eq!(
+            date(2024, 10, 1).snap_to_workday_forward(),
+            Some(date(2024, 10, 8))
+        );
+        assert_eq!(
+            date(2024, 10, 1).snap_to_workday_backward(),
+            Some(date(2024, 9, 30))
+        );
+        assert_eq!(
+            date(2024, 10, 12).snap_to_workday_forward(),
+            Some(date(2024, 10, 12))
+        );
+        assert_eq!(
+            date(2024, 10, 12).snap_to_workday_backward(),
+            Some(date(2024, 10, 12))
+        );
+        assert_eq!(
+            date(2024, 9, 28).snap_to_workday_forward(),
+            Some(date(2024, 9, 29))
+        );
+        assert_eq!(
+            date(2024, 12, 29).snap_to_workday_forward(),
+            Some(date(2024, 12, 30))
+        );
+        assert_eq!(date(2024, 1, 1).snap_to_workday_backward(), None);
+        assert_eq!(date(2023, 10, 1).snap_to_workday_forward(), None);
+    }
+
+    #[test]
+    fn test_holiday_date_range() {
+        assert!(HolidayDateRange::new(date(2024, 10, 2), date(2024, 10, 1)).is_none());
+        let range = HolidayDateRange::new(date(2024, 10, 1), date(2024, 10, 1)).unwrap();
+        assert_eq!(range.len(), 1);
+        assert!(!range.is_empty());
+        assert_eq!(range.workdays().count(), 0);
+        let range = HolidayDateRange::new(date(2024, 9, 30), date(2024, 10, 13)).unwrap();
+        assert_eq!(range.len(), 14);
+        assert_eq!(range.iter().count(), 14);
+        assert_eq!(range.holidays().count(), 8);
+        assert_eq!(range.workdays().count(), 6);
+        assert!(range.contains(&date(2024, 10, 13)));
+        assert!(!range.contains(&date(2024, 10, 14)));
+        let range = HolidayDateRange::new(date(2024, 12, 30), date(2025, 1, 2)).unwrap();
+        assert_eq!(range.len(), 4);
+        assert_eq!(range.workdays().count() + range.holidays().count(), 2);
+    }
+
+    #[test]
+    fn test_rest_ratio() {
+        let february = rest_ratio(2024, 2).unwrap();
+        let march = rest_ratio(2024, 3).unwrap();
+        assert_eq!(february, 11.0 / 29.0);
+        assert_eq!(march, 10.0 / 31.0);
+        assert!(february > march);
+        assert_eq!(rest_ratio(2024, 13), None);
+        assert_eq!(rest_ratio(2023, 12), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_holidays_in_iso_week() {
+        let week = date(2024, 10, 1).iso_week();
+        assert_eq!(week, (2024, 40));
+        let days_off = holidays_in_iso_week(week.0, week.1).unwrap();
+        assert_eq!(days_off.len(), 6);
+        assert_eq!(days_off[0], (date(2024, 10, 1), HolidayKind::G1001Holiday));
+        assert_eq!(
+            days_off[5],
+            (date(2024, 10, 6), HolidayKind::RegularHoliday)
+        );
+        let days_off = holidays_in_iso_week(2024, 41).unwrap();
+        assert_eq!(
+            days_off,
+            [
+                (date(2024, 10, 7), HolidayKind::G1001Holiday),
+                (date(2024, 10, 13), HolidayKind::RegularHoliday),
+            ]
+        );
+        assert_eq!(holidays_in_iso_week(2024, 1).unwrap().len(), 3);
+        assert_eq!(holidays_in_iso_week(2024, 53), None);
+        assert_eq!(holidays_in_iso_week(2024, 0), None);
+        assert_eq!(holidays_in_iso_week(2025, 1), None);
+    }
+
+    #[test]
+    fn test_range_summary() {
+        let summary = range_summary(date(2024, 1, 1), date(2024, 12, 31)).unwrap();
+        assert_eq!(
+            summary,
+            RangeSummary {
+                total_days: 366,
+                workdays: 251,
+                days_off: 115,
+                makeup_workdays: 8,
+                statutory_holidays: 19,
+            }
+        );
+        assert_eq!(summary.workdays + summary.days_off, summary.total_days);
+        assert_eq!(
+            summary.statutory_holidays as i32 - summary.makeup_workdays as i32,
+            net_extra_rest_days(2024).unwrap()
+        );
+        let summary = range_summary(date(2024, 9, 29), date(2024, 10, 12)).unwrap();
+        assert_eq!(summary.total_days, 14);
+        assert_eq!(summary.makeup_workdays, 2);
+        assert_eq!(summary.statutory_holidays, 5);
+        assert_eq!(range_summary(date(2024, 10, 2), date(2024, 10, 1)), None);
+        assert_eq!(range_summary(date(2023, 12, 31), date(2024, 1, 1)), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_kind_histogram() {
+        let histogram = kind_histogram(2024).unwrap();
+        assert_eq!(histogram.values().sum::<u32>(), 366);
+        assert_eq!(histogram["RegularHoliday"], 96);
+        assert_eq!(histogram["RegularWorkday"], 243);
+        assert_eq!(histogram["G0101Holiday"], 1);
+        assert_eq!(histogram["G1001Holiday"], 5);
+        assert_eq!(histogram["G1001Workday"], 2);
+        assert!(!histogram.contains_key("G0101Workday"));
+        assert_eq!(kind_histogram(2023), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_long_weekends() {
+        assert_eq!(
+            long_weekends(date(2024, 1, 1), date(2024, 6, 30), 3),
+            [
+                (date(2024, 2, 10), date(2024, 2, 17)),
+                (date(2024, 4, 4), date(2024, 4, 6)),
+                (date(2024, 5, 1), date(2024, 5, 5)),
+                (date(2024, 6, 8), date(2024, 6, 10)),
+            ]
+        );
+        assert_eq!(
+            long_weekends(date(2024, 1, 1), date(2024, 6, 30), 6),
+            [(date(2024, 2, 10), date(2024, 2, 17))]
+        );
+        assert_eq!(
+            long_weekends(date(2024, 10, 3), date(2024, 10, 5), 3),
+            [(date(2024, 10, 3), date(2024, 10, 5))]
+        );
+        assert!(long_weekends(date(2024, 7, 1), date(2024, 8, 31), 3).is_empty());
+        assert!(long_weekends(date(2024, 7, 1), date(2024, 6, 30), 3).is_empty());
+        assert!(long_weekends(date(2023, 12, 1), date(2024, 6, 30), 3).is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_makeup_workdays_in_month() {
+        assert_eq!(
+            makeup_workdays_in_month(2024, 9),
+            [date(2024, 9, 14), date(2024, 9, 29)]
+        );
+        assert_eq!(makeup_workdays_in_month(2024, 10), [date(2024, 10, 12)]);
+        assert!(makeup_workdays_in_month(2024, 3).is_empty());
+        assert!(makeup_workdays_in_month(2024, 13).is_empty());
+        assert!(makeup_workdays_in_month(2023, 9).is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_filter_year() {
+        let festival_holidays = filter_year(2024, |kind| kind.is_festival_holiday()).unwrap();
+        assert_eq!(festival_holidays.len(), 19);
+        assert_eq!(festival_holidays[0], date(2024, 1, 1));
+        let national_day = filter_year(2024, |kind| {
+            matches!(kind, HolidayKind::G1001Holiday | HolidayKind::G1001Workday)
+        })
+        .unwrap();
+        assert_eq!(national_day.len(), 7);
+        assert_eq!(national_day[0], date(2024, 9, 29));
+        assert_eq!(filter_year(2024, |_| true).unwrap().len(), 366);
+        assert_eq!(filter_year(2023, |_| true), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_next_workdays() {
+        assert_eq!(
+            date(2024, 9, 30).next_workdays(5),
+            [
+                date(2024, 10, 8),
+                date(2024, 10, 9),
+                date(2024, 10, 10),
+                date(2024, 10, 11),
+                date(2024, 10, 12),
+            ]
+        );
+        assert_eq!(
+            date(2024, 9, 28).next_workdays(2),
+            [date(2024, 9, 29), date(2024, 9, 30)]
+        );
+        assert!(date(2024, 9, 30).next_workdays(0).is_empty());
+        assert_eq!(date(2024, 12, 30).next_workdays(5), [date(2024, 12, 31)]);
+        assert_eq!(date(2023, 12, 31).next_workdays(1), [date(2024, 1, 2)]);
+    }
+
+    #[test]
+    fn test_working_hours_between() {
+        assert_eq!(
+            working_hours_between(date(2024, 10, 1), date(2024, 10, 13), 8.0),
+            Some(40.0)
+        );
+        assert_eq!(
+            working_hours_between(date(2024, 9, 29), date(2024, 9, 30), 7.5),
+            Some(15.0)
+        );
+        assert_eq!(
+            working_hours_between(date(2024, 10, 1), date(2024, 10, 7), 8.0),
+            Some(0.0)
+        );
+        assert_eq!(
+            working_hours_between(date(2024, 10, 2), date(2024, 10, 1), 8.0),
+            None
+        );
+        assert_eq!(
+            working_hours_between(date(2024, 12, 31), date(2025, 1, 2), 8.0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_contains_holiday() {
+        assert_eq!(
+            contains_holiday(date(2024, 10, 14), date(2024, 10, 15)),
+            Some(false)
+        );
+        assert_eq!(
+            contains_holiday(date(2024, 10, 8), date(2024, 10, 12)),
+            Some(false)
+        );
+        assert_eq!(
+            contains_holiday(date(2024, 10, 17), date(2024, 10, 21)),
+            Some(true)
+        );
+        assert_eq!(
+            contains_holiday(date(2024, 10, 1), date(2024, 10, 1)),
+            Some(true)
+        );
+        assert_eq!(
+            contains_holiday(date(2024, 10, 21), date(2024, 10, 17)),
+            None
+        );
+        assert_eq!(contains_holiday(date(2023, 12, 31), date(2024, 1, 1)), None);
+    }
+
+    #[test]
+    fn test_net_extra_rest_days() {
+        assert_eq!(net_extra_rest_days(2024), Some(11));
+        assert_eq!(net_extra_rest_days(2023), None);
+    }
+
+    #[test]
+    fn test_statutory_impact() {
+        let impact = statutory_impact(2024).unwrap();
+        assert_eq!(
+            impact,
+            StatutoryImpact {
+                extra_days_off: 19,
+                weekends_worked: 8,
+            }
+        );
+        assert_eq!(
+            impact.extra_days_off as i32 - impact.weekends_worked as i32,
+            net_extra_rest_days(2024).unwrap()
+        );
+        assert_eq!(statutory_impact(2023), None);
+    }
+
+    #[test]
+    fn test_first_last_holiday_of_year() {
+        assert_eq!(first_holiday_of_year(2024), Some(date(2024, 1, 1)));
+        assert_eq!(last_holiday_of_year(2024), Some(date(2024, 12, 29)));
+        assert_eq!(first_holiday_of_year(2023), None);
+        assert_eq!(last_holiday_of_year(2025), None);
+    }
+
+    #[test]
+    fn test_year_calendar() {
+        assert_eq!(year_calendar(2024).unwrap().count(), 366);
+        assert_eq!(
+            year_calendar(2024).unwrap().filter(|(_, off)| *off).count(),
+            115
+        );
+        for (date, off) in year_calendar(2024).unwrap() {
+            assert_eq!(date.is_holiday(), Some(off), "{date}");
+        }
+        assert!(year_calendar(2023).is_none());
+    }
+
+    #[test]
+    fn test_transitions_in_year() {
+        let mut transitions = transitions_in_year(2024).unwrap();
+        assert_eq!(transitions.next(), Some((date(2024, 1, 1), true)));
+        assert_eq!(transitions.next(), Some((date(2024, 1, 2), false)));
+        assert_eq!(transitions.next(), Some((date(2024, 1, 6), true)));
+        let mut prev = false;
+        for (date, status) in transitions_in_year(2024).unwrap() {
+            assert_ne!(status, prev, "{date}");
+            prev = status;
+        }
+        let mut transitions = transitions_in_year(2024)
+            .unwrap()
+            .skip_while(|(d, _)| *d < date(2024, 9, 28));
+        assert_eq!(transitions.next(), Some((date(2024, 9, 28), true)));
+        assert_eq!(transitions.next(), Some((date(2024, 9, 29), false)));
+        assert_eq!(transitions.next(), Some((date(2024, 10, 1), true)));
+        assert_eq!(transitions.next(), Some((date(2024, 10, 8), false)));
+        assert!(transitions_in_year(2023).is_none());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_year_map() {
+        let map = year_map(2024).unwrap();
+        assert_eq!(map.len(), 27);
+        let october: Vec<_> = map
+            .range(date(2024, 10, 1)..=date(2024, 10, 31))
+            .map(|(date, _)| *date)
+            .collect();
+        assert_eq!(october.len(), 6);
+        assert_eq!(
+            map.get(&date(2024, 10, 12)),
+            Some(&HolidayKind::G1001Workday)
+        );
+        assert_eq!(map.get(&date(2024, 10, 5)), None);
+        assert!(year_map(2023).is_none());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_upcoming_days_off() {
+        let mut expected = alloc::vec![date(2024, 9, 28)];
+        expected.extend((1..=7).map(|day| date(2024, 10, day)));
+        assert_eq!(date(2024, 9, 23).upcoming_days_off(14), expected);
+        assert!(date(2024, 10, 8).upcoming_days_off(3).is_empty());
+        assert_eq!(
+            date(2024, 12, 25).upcoming_days_off(30),
+            [date(2024, 12, 28), date(2024, 12, 29)]
+        );
+        assert_eq!(
+            date(2024, 12, 25).upcoming_days_off(u32::MAX),
+            [date(2024, 12, 28), date(2024, 12, 29)]
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt::Display;
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{HolidayCalendar, HolidayDate, HolidayError, HolidayKind};
+
+fn deserialize_str<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: core::str::FromStr<Err: Display>,
+{
+    String::deserialize(deserializer)?
+        .parse()
+        .map_err(de::Error::custom)
+}
+
+/// Serializes as `YYYY-MM-DD`.
+impl Serialize for HolidayDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for HolidayDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_str(deserializer)
+    }
+}
+
+/// Serializes as the variant name, e.g. `G1001Holiday`.
+impl Serialize for HolidayKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for HolidayKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_str(deserializer)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Record {
+    date: HolidayDate,
+    kind: HolidayKind,
+}
+
+/// Serializes the custom records as a sequence of `{ date, kind }` in ascending order of date.
+impl Serialize for HolidayCalendar {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.records().iter().map(|(date, kind)| Record {
+            date: *date,
+            kind: *kind,
+        }))
+    }
+}
+
+/// Deserializes custom records validated as in [`HolidayCalendar::from_records`], and requires
+/// them in ascending order of date.
+impl<'de> Deserialize<'de> for HolidayCalendar {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let records = Vec::<Record>::deserialize(deserializer)?;
+        if !records.windows(2).all(|w| w[0].date < w[1].date) {
+            return Err(de::Error::custom(HolidayError::UnsortedRecords));
+        }
+        HolidayCalendar::from_records(records.into_iter().map(|r| (r.date, r.kind)))
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calendar_round_trip() {
+        let date = |s: &str| s.parse::<HolidayDate>().unwrap();
+        let calendar = HolidayCalendar::from_records([
+            (date("2025-10-01"), HolidayKind::G1001Holiday),
+            (date("2024-10-12"), HolidayKind::RegularHoliday),
+        ])
+        .unwrap();
+        let json = serde_json::to_string(&calendar).unwrap();
+        assert_eq!(
+            json,
+            r#"[{"date":"2024-10-12","kind":"RegularHoliday"},{"date":"2025-10-01","kind":"G1001Holiday"}]"#
+        );
+        let calendar: HolidayCalendar = serde_json::from_str(&json).unwrap();
+        assert_eq!(calendar.is_holiday(date("2024-10-12")), Some(true));
+        assert_eq!(calendar.is_holiday(date("2025-10-01")), Some(true));
+        assert_eq!(calendar.is_holiday(date("2025-10-02")), Some(false));
+    }
+
+    #[test]
+    fn test_calendar_validation() {
+        assert!(serde_json::from_str::<HolidayCalendar>(
+            r#"[{"date":"2025-10-01","kind":"G1001Holiday"},{"date":"2024-10-12","kind":"RegularHoliday"}]"#
+        )
+        .is_err());
+        assert!(serde_json::from_str::<HolidayCalendar>(
+            r#"[{"date":"2025-10-04","kind":"G1001Holiday"}]"#
+        )
+        .is_err());
+        assert!(serde_json::from_str::<HolidayCalendar>(
+            r#"[{"date":"2025-10-01","kind":"Unknown"}]"#
+        )
+        .is_err());
+    }
+}
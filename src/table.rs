@@ -0,0 +1,124 @@
+use alloc::vec::Vec;
+
+use crate::{HolidayDate, HolidayError, HolidayKind};
+
+/// A table of holiday kinds by date, sorted and without duplicate dates.
+///
+/// Collecting with [`FromIterator`] keeps the last kind of a date recorded more than once. Use
+/// [`try_from_iter`](Self::try_from_iter) to reject such conflicts instead.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HolidayTable {
+    records: Vec<(HolidayDate, HolidayKind)>,
+}
+
+impl HolidayTable {
+    /// Constructs from records in any order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HolidayError::DuplicateDate`] when a date is recorded with different kinds.
+    pub fn try_from_iter(
+        iter: impl IntoIterator<Item = (HolidayDate, HolidayKind)>,
+    ) -> Result<Self, HolidayError> {
+        let mut records: Vec<_> = iter.into_iter().collect();
+        records.sort_by_key(|(date, _)| *date);
+        if let Some(w) = records
+            .windows(2)
+            .find(|w| w[0].0 == w[1].0 && w[0].1 != w[1].1)
+        {
+            return Err(HolidayError::DuplicateDate(w[0].0));
+        }
+        records.dedup_by_key(|(date, _)| *date);
+        Ok(Self { records })
+    }
+
+    /// Returns the recorded kind of the date.
+    pub fn get(&self, date: HolidayDate) -> Option<&HolidayKind> {
+        self.records
+            .binary_search_by_key(&date, |(d, _)| *d)
+            .ok()
+            .map(|i| &self.records[i].1)
+    }
+
+    /// Returns whether any record is in the year.
+    pub(crate) fn has_year(&self, year: u16) -> bool {
+        let i = self.records.partition_point(|(d, _)| d.year < year);
+        self.records.get(i).is_some_and(|(d, _)| d.year == year)
+    }
+
+    /// Returns the number of records.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns whether there are no records.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Returns the records sorted by date.
+    pub fn iter(&self) -> impl Iterator<Item = &(HolidayDate, HolidayKind)> {
+        self.records.iter()
+    }
+}
+
+impl FromIterator<(HolidayDate, HolidayKind)> for HolidayTable {
+    fn from_iter<T: IntoIterator<Item = (HolidayDate, HolidayKind)>>(iter: T) -> Self {
+        let mut records: Vec<_> = iter.into_iter().collect();
+        // Stable sort keeps records of the same date in input order.
+        records.sort_by_key(|(date, _)| *date);
+        records.reverse();
+        records.dedup_by_key(|(date, _)| *date);
+        records.reverse();
+        Self { records }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> HolidayDate {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let table: HolidayTable = [
+            (date("2025-10-01"), HolidayKind::G1001Holiday),
+            (date("2025-01-01"), HolidayKind::G0101Holiday),
+            (date("2025-10-01"), HolidayKind::RegularWorkday),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(table.len(), 2);
+        assert_eq!(
+            table.get(date("2025-01-01")),
+            Some(&HolidayKind::G0101Holiday)
+        );
+        assert_eq!(
+            table.get(date("2025-10-01")),
+            Some(&HolidayKind::RegularWorkday)
+        );
+        assert_eq!(table.get(date("2025-10-02")), None);
+        assert!(table.iter().is_sorted_by_key(|(date, _)| *date));
+    }
+
+    #[test]
+    fn test_try_from_iter() {
+        let table = HolidayTable::try_from_iter([
+            (date("2025-10-01"), HolidayKind::G1001Holiday),
+            (date("2025-01-01"), HolidayKind::G0101Holiday),
+            (date("2025-10-01"), HolidayKind::G1001Holiday),
+        ])
+        .unwrap();
+        assert_eq!(table.len(), 2);
+        assert!(matches!(
+            HolidayTable::try_from_iter([
+                (date("2025-10-01"), HolidayKind::G1001Holiday),
+                (date("2025-10-01"), HolidayKind::RegularWorkday),
+            ]),
+            Err(HolidayError::DuplicateDate(d)) if d == date("2025-10-01")
+        ));
+    }
+}
@@ -0,0 +1,77 @@
+//! Assertions for tests of code depending on this crate.
+//!
+//! ```
+//! use chinese_mainland_holidays::{test_util, HolidayDate};
+//!
+//! test_util::assert_holiday(HolidayDate::from_ymd(2024, 10, 1).unwrap());
+//! test_util::assert_workday(HolidayDate::from_ymd(2024, 10, 12).unwrap());
+//! ```
+
+use crate::{HolidayDate, HolidayLike};
+
+/// Asserts that the date is a day off.
+///
+/// # Panics
+///
+/// Panics with the date and its kind when the date is a working day, or when its year is not
+/// supported.
+#[track_caller]
+pub fn assert_holiday(date: HolidayDate) {
+    assert_is_holiday(date, true);
+}
+
+/// Asserts that the date is a working day.
+///
+/// # Panics
+///
+/// Panics with the date and its kind when the date is a day off, or when its year is not
+/// supported.
+#[track_caller]
+pub fn assert_workday(date: HolidayDate) {
+    assert_is_holiday(date, false);
+}
+
+#[track_caller]
+fn assert_is_holiday(date: HolidayDate, expected: bool) {
+    let expected_name = if expected { "a holiday" } else { "a workday" };
+    match date.holiday_kind() {
+        Some(kind) if kind.is_day_off() == expected => {}
+        Some(kind) => panic!("expected {date} to be {expected_name}, but it is {kind}"),
+        None => panic!("expected {date} to be {expected_name}, but its year is not supported"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> HolidayDate {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_assert() {
+        assert_holiday(date("2024-10-07"));
+        assert_holiday(date("2024-10-13"));
+        assert_workday(date("2024-10-12"));
+        assert_workday(date("2024-10-14"));
+    }
+
+    #[test]
+    #[should_panic(expected = "expected 2024-10-12 to be a holiday, but it is G1001Workday")]
+    fn test_assert_holiday_fails() {
+        assert_holiday(date("2024-10-12"));
+    }
+
+    #[test]
+    #[should_panic(expected = "expected 2024-10-01 to be a workday, but it is G1001Holiday")]
+    fn test_assert_workday_fails() {
+        assert_workday(date("2024-10-01"));
+    }
+
+    #[test]
+    #[should_panic(expected = "expected 2023-10-09 to be a workday, but its year is not supported")]
+    fn test_assert_unsupported() {
+        assert_workday(date("2023-10-09"));
+    }
+}
@@ -0,0 +1,55 @@
+use time::Date;
+
+use crate::{HolidayDate, HolidayKind, HolidayLike};
+
+impl HolidayDate {
+    /// Converts from a `time` date, usable in `const` context together with
+    /// [`holiday_kind_of`](crate::holiday_kind_of) on any toolchain building this crate.
+    ///
+    /// ```
+    /// use chinese_mainland_holidays::{holiday_kind_of, HolidayDate, HolidayKind};
+    /// use time::macros::date;
+    ///
+    /// const KIND: Option<HolidayKind> = holiday_kind_of(HolidayDate::from_time(date!(2024-10-01)));
+    /// assert_eq!(KIND, Some(HolidayKind::G1001Holiday));
+    /// ```
+    #[inline]
+    pub const fn from_time(date: Date) -> Self {
+        Self {
+            year: date.year() as u16,
+            month: date.month() as u8,
+            day: date.day(),
+        }
+    }
+}
+
+impl From<Date> for HolidayDate {
+    #[inline]
+    fn from(value: Date) -> Self {
+        Self::from_time(value)
+    }
+}
+
+impl HolidayLike for Date {
+    #[inline]
+    fn holiday_kind(&self) -> Option<HolidayKind> {
+        HolidayDate::from_time(*self).holiday_kind()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::date;
+
+    use super::*;
+
+    #[test]
+    fn test_from_time() {
+        const DATE: HolidayDate = HolidayDate::from_time(date!(2024 - 10 - 01));
+        assert_eq!(DATE, HolidayDate::from_ymd(2024, 10, 1).unwrap());
+        const KIND: Option<HolidayKind> = crate::holiday_kind_of(DATE);
+        assert_eq!(KIND, Some(HolidayKind::G1001Holiday));
+        assert_eq!(date!(2024 - 10 - 12).is_holiday(), Some(false));
+        assert_eq!(date!(2023 - 10 - 01).holiday_kind(), None);
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{HolidayDate, HolidayKind, HolidayLike};
+
+/// Number of days from 0001-01-01 to 1970-01-01.
+const UNIX_EPOCH_DAY_NUMBER: i64 = 719162;
+/// Offset of China Standard Time from UTC in seconds.
+const CST_OFFSET: i64 = 8 * 3600;
+
+/// Returns the civil day in China Standard Time of a Unix timestamp in seconds.
+fn civil_day(secs: i64) -> Option<HolidayDate> {
+    let days = secs.checked_add(CST_OFFSET)?.div_euclid(86400);
+    HolidayDate::from_day_number(days.checked_add(UNIX_EPOCH_DAY_NUMBER)?.try_into().ok()?)
+}
+
+/// Returns the holiday kind of the civil day in China Standard Time of a Unix timestamp in
+/// seconds.
+///
+/// # Errors
+///
+/// Returns `None` when the year is less than [`MIN_YEAR`](crate::MIN_YEAR) or greater than
+/// [`MAX_YEAR`](crate::MAX_YEAR).
+pub fn holiday_kind_unix(secs: i64) -> Option<HolidayKind> {
+    civil_day(secs)?.holiday_kind()
+}
+
+/// Returns whether the civil day in China Standard Time of a Unix timestamp in seconds is a
+/// holiday.
+///
+/// # Errors
+///
+/// Returns `None` when the year is less than [`MIN_YEAR`](crate::MIN_YEAR) or greater than
+/// [`MAX_YEAR`](crate::MAX_YEAR).
+pub fn is_holiday_unix(secs: i64) -> Option<bool> {
+    civil_day(secs)?.is_holiday()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_civil_day() {
+        assert_eq!(civil_day(0), HolidayDate::from_ymd(1970, 1, 1));
+        assert_eq!(
+            civil_day(-CST_OFFSET - 1),
+            HolidayDate::from_ymd(1969, 12, 31)
+        );
+        assert_eq!(civil_day(i64::MIN), None);
+        assert_eq!(civil_day(i64::MAX), None);
+    }
+
+    #[test]
+    fn test_holiday_kind_unix() {
+        // 2024-09-30T16:00:00Z, 2024-10-01T00:00:00+08:00
+        assert_eq!(
+            holiday_kind_unix(1727712000),
+            Some(HolidayKind::G1001Holiday)
+        );
+        assert_eq!(is_holiday_unix(1727712000), Some(true));
+        assert_eq!(is_holiday_unix(1727712000 - 1), Some(false));
+        assert_eq!(is_holiday_unix(-1), None);
+    }
+}
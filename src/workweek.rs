@@ -0,0 +1,172 @@
+use crate::{HolidayDate, HolidayKind, HolidayLike};
+
+/// The regular rest days of a week, for offices not resting on Saturday and Sunday.
+///
+/// Recorded festival holidays and adjusted working days apply regardless of the rest days, and so
+/// do Saturdays and Sundays bridged into a festival break. Other days follow the rest days.
+///
+/// ```
+/// use chinese_mainland_holidays::{HolidayDate, HolidayKind, WorkweekConfig};
+///
+/// // Friday and Saturday.
+/// let config = WorkweekConfig::new([false, false, false, false, false, true, true]);
+/// let date = HolidayDate::from_ymd(2024, 10, 13).unwrap();
+/// assert_eq!(date.holiday_kind_with(&config), Some(HolidayKind::RegularWorkday));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkweekConfig {
+    rest_days: [bool; 7],
+}
+
+impl WorkweekConfig {
+    /// Constructs from whether each day of week is a rest day, indexed from Sunday as 0 to
+    /// Saturday as 6.
+    pub const fn new(rest_days: [bool; 7]) -> Self {
+        Self { rest_days }
+    }
+
+    /// Returns whether each day of week is a rest day, indexed from Sunday as 0.
+    pub const fn rest_days(&self) -> [bool; 7] {
+        self.rest_days
+    }
+}
+
+impl Default for WorkweekConfig {
+    /// Rests on Saturday and Sunday, classifying every date as [`HolidayLike::holiday_kind`] does.
+    fn default() -> Self {
+        Self::new([true, false, false, false, false, false, true])
+    }
+}
+
+/// How regular Saturdays are worked, for institutions on a five-and-a-half-day week.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WeekendPolicy {
+    /// Rests on Saturday and Sunday.
+    #[default]
+    FullWeekend,
+    /// Works half of regular Saturdays and rests on Sunday.
+    HalfSaturday,
+}
+
+/// The holiday kind of a date under a [`WeekendPolicy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PolicyKind {
+    /// The holiday kind as recorded, or the regular kind.
+    Kind(HolidayKind),
+    /// A regular Saturday worked for half of the day.
+    HalfWorkday,
+}
+
+impl HolidayDate {
+    /// Returns the holiday kind of the date under the given rest days.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when the year is less than [`MIN_YEAR`](crate::MIN_YEAR) or greater than
+    /// [`MAX_YEAR`](crate::MAX_YEAR).
+    pub fn holiday_kind_with(&self, config: &WorkweekConfig) -> Option<HolidayKind> {
+        match self.holiday_kind()? {
+            HolidayKind::RegularHoliday | HolidayKind::RegularWorkday
+                if self.break_festival_of().is_none() =>
+            {
+                Some(if config.rest_days[self.num_days_from_sunday() as usize] {
+                    HolidayKind::RegularHoliday
+                } else {
+                    HolidayKind::RegularWorkday
+                })
+            }
+            kind => Some(kind),
+        }
+    }
+
+    /// Returns the holiday kind of the date under the given weekend policy.
+    ///
+    /// The policy only affects the fallback for regular Saturdays. Recorded festival holidays and
+    /// adjusted working days apply regardless, and so does a Saturday bridged into a break.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when the year is less than [`MIN_YEAR`](crate::MIN_YEAR) or greater than
+    /// [`MAX_YEAR`](crate::MAX_YEAR).
+    pub fn holiday_kind_with_policy(&self, policy: WeekendPolicy) -> Option<PolicyKind> {
+        let kind = self.holiday_kind()?;
+        let half = policy == WeekendPolicy::HalfSaturday
+            && kind == HolidayKind::RegularHoliday
+            && self.num_days_from_sunday() == 6
+            && self.break_festival_of().is_none();
+        Some(if half {
+            PolicyKind::HalfWorkday
+        } else {
+            PolicyKind::Kind(kind)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: u16, month: u8, day: u8) -> HolidayDate {
+        HolidayDate::from_ymd(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_holiday_kind_with_policy() {
+        let kind = |d: HolidayDate| d.holiday_kind_with_policy(WeekendPolicy::HalfSaturday);
+        assert_eq!(kind(date(2024, 10, 19)), Some(PolicyKind::HalfWorkday));
+        assert_eq!(
+            kind(date(2024, 10, 20)),
+            Some(PolicyKind::Kind(HolidayKind::RegularHoliday))
+        );
+        assert_eq!(
+            kind(date(2024, 10, 12)),
+            Some(PolicyKind::Kind(HolidayKind::G1001Workday))
+        );
+        assert_eq!(
+            kind(date(2024, 10, 5)),
+            Some(PolicyKind::Kind(HolidayKind::RegularHoliday))
+        );
+        assert_eq!(
+            kind(date(2024, 10, 18)),
+            Some(PolicyKind::Kind(HolidayKind::RegularWorkday))
+        );
+        assert_eq!(kind(date(2023, 10, 21)), None);
+        let d = date(2024, 10, 19);
+        assert_eq!(
+            d.holiday_kind_with_policy(WeekendPolicy::default()),
+            Some(PolicyKind::Kind(HolidayKind::RegularHoliday))
+        );
+    }
+
+    #[test]
+    fn test_holiday_kind_with() {
+        let friday_saturday = WorkweekConfig::new([false, false, false, false, false, true, true]);
+        let kind = |d: HolidayDate| d.holiday_kind_with(&friday_saturday);
+        assert_eq!(kind(date(2024, 6, 10)), Some(HolidayKind::L0505Holiday));
+        assert_eq!(kind(date(2024, 9, 16)), Some(HolidayKind::L0815Holiday));
+        assert_eq!(kind(date(2024, 10, 5)), Some(HolidayKind::RegularHoliday));
+        assert_eq!(kind(date(2024, 10, 6)), Some(HolidayKind::RegularHoliday));
+        assert_eq!(kind(date(2024, 10, 11)), Some(HolidayKind::RegularHoliday));
+        assert_eq!(kind(date(2024, 10, 12)), Some(HolidayKind::G1001Workday));
+        assert_eq!(kind(date(2024, 10, 13)), Some(HolidayKind::RegularWorkday));
+        assert_eq!(kind(date(2024, 10, 14)), Some(HolidayKind::RegularWorkday));
+        assert_eq!(kind(date(2023, 10, 13)), None);
+
+        let saturday_sunday = WorkweekConfig::default();
+        assert_eq!(
+            saturday_sunday.rest_days(),
+            [true, false, false, false, false, false, true]
+        );
+        let mut d = date(2024, 1, 1);
+        while d < date(2024, 12, 31) {
+            assert_eq!(
+                d.holiday_kind_with(&saturday_sunday),
+                d.holiday_kind(),
+                "{d}"
+            );
+            d = d.succ().unwrap();
+        }
+    }
+}
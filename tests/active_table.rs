@@ -0,0 +1,73 @@
+#![cfg(feature = "std")]
+
+use chinese_mainland_holidays::{
+    active_table, print_year, set_active_table, text_calendar, year_calendar, HolidayCalendar,
+    HolidayDate, HolidayKind, HolidayLike, HolidayTable,
+};
+
+#[test]
+fn test_set_active_table() {
+    let date = |s: &str| s.parse::<HolidayDate>().unwrap();
+    // Built-in records apply until the table is set.
+    assert_eq!(
+        date("2024-10-01").holiday_kind(),
+        Some(HolidayKind::G1001Holiday)
+    );
+    assert_eq!(date("2025-10-01").holiday_kind(), None);
+
+    let table = HolidayTable::try_from_iter([
+        (date("2024-12-31"), HolidayKind::G0101Holiday),
+        (date("2025-01-01"), HolidayKind::G0101Holiday),
+        (date("2025-09-28"), HolidayKind::G1001Workday),
+        (date("2025-10-01"), HolidayKind::G1001Holiday),
+    ])
+    .unwrap();
+    set_active_table(table.clone()).unwrap();
+    assert_eq!(active_table(), &table);
+    assert_eq!(
+        set_active_table(HolidayTable::default()),
+        Err(HolidayTable::default())
+    );
+
+    assert_eq!(
+        date("2025-10-01").holiday_kind(),
+        Some(HolidayKind::G1001Holiday)
+    );
+    assert_eq!(date("2025-10-01").is_holiday(), Some(true));
+    assert_eq!(date("2025-09-28").is_holiday(), Some(false));
+    assert_eq!(
+        date("2025-10-08").holiday_kind(),
+        Some(HolidayKind::RegularWorkday)
+    );
+    assert_eq!(date("2025-10-11").is_holiday(), Some(true));
+    // The built-in records are replaced, so 2024 only has the recorded 2024-12-31.
+    assert_eq!(
+        date("2024-10-01").holiday_kind(),
+        Some(HolidayKind::RegularWorkday)
+    );
+    assert_eq!(date("2024-12-31").is_holiday(), Some(true));
+    assert_eq!(date("2023-10-01").is_holiday(), None);
+    assert_eq!(date("2026-10-01").is_holiday(), None);
+
+    let october = text_calendar(2025, 10).unwrap();
+    assert!(october.contains("  1H  2   3   4*  5*\n"), "{october}");
+    assert!(text_calendar(2025, 9).unwrap().contains(" 28W\n"));
+    assert!(text_calendar(2024, 10)
+        .unwrap()
+        .contains("  1   2   3   4   5*"));
+    assert!(print_year(2025).unwrap().starts_with("Jan 2025\n"));
+    assert_eq!(print_year(2023), None);
+
+    let days: Vec<_> = year_calendar(2024).unwrap().collect();
+    assert_eq!(days.len(), 366);
+    for (date, off) in days {
+        assert_eq!(date.is_holiday(), Some(off), "{date}");
+    }
+    // Years beyond the built-in ones stay unsupported by `year_calendar`.
+    assert!(year_calendar(2025).is_none());
+
+    let calendar = HolidayCalendar::new();
+    assert_eq!(calendar.is_holiday(date("2025-10-01")), Some(true));
+    assert_eq!(calendar.is_holiday(date("2024-10-01")), Some(false));
+    assert_eq!(calendar.is_holiday(date("2023-10-01")), None);
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "cli")]
+
+use std::process::{Command, Output};
+
+fn holiday(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_holiday"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn test_holiday() {
+    let output = holiday(&["2024-10-01"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(output.stdout, b"holiday (National Day)\n");
+    let output = holiday(&["2024-10-05"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(output.stdout, b"holiday (National Day)\n");
+    let output = holiday(&["2024-10-19"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(output.stdout, b"holiday\n");
+}
+
+#[test]
+fn test_workday() {
+    let output = holiday(&["2024-10-08"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(output.stdout, b"workday\n");
+    let output = holiday(&["2024-10-12"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(output.stdout, b"workday (National Day)\n");
+}
+
+#[test]
+fn test_json() {
+    let output = holiday(&["--json", "2024-10-01"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "{\"date\":\"2024-10-01\",\"holiday\":true,\"kind\":\"G1001Holiday\",\"festival\":\"National Day\"}\n"
+    );
+    let output = holiday(&["2024-10-08", "--json"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "{\"date\":\"2024-10-08\",\"holiday\":false,\"kind\":\"RegularWorkday\",\"festival\":null}\n"
+    );
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(holiday(&[]).status.code(), Some(2));
+    assert_eq!(holiday(&["2024-13-01"]).status.code(), Some(2));
+    assert_eq!(holiday(&["2023-10-01"]).status.code(), Some(2));
+}
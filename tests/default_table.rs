@@ -0,0 +1,19 @@
+#![cfg(feature = "std")]
+
+//! Runs in its own process, since reading the active table fixes it for the rest of the process.
+
+use chinese_mainland_holidays::{
+    active_table, set_active_table, year_map, HolidayDate, HolidayKind, HolidayTable, MAX_YEAR,
+    MIN_YEAR,
+};
+
+#[test]
+fn test_default_active_table() {
+    let table = active_table();
+    let records = (MIN_YEAR..=MAX_YEAR).map(|year| year_map(year).unwrap().len());
+    assert_eq!(table.len(), records.sum::<usize>());
+    let date = HolidayDate::from_ymd(2024, 10, 1).unwrap();
+    assert_eq!(table.get(date), Some(&HolidayKind::G1001Holiday));
+    assert!(set_active_table(HolidayTable::default()).is_err());
+    assert_eq!(active_table(), table);
+}